@@ -0,0 +1,33 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let years = vec![2018., 2019., 2020., 2021., 2022.];
+    let chart = CartesianGroup::new()
+        .set_view(840, 640, 3, 50, 50, 20)
+        .add_data(
+            Series::from(years.clone()).set_range(2018., 2022.),
+            Series::from(vec![3., 4., 2., 5., 6.]),
+        )
+        .add_data(
+            Series::from(years.clone()).set_range(2018., 2022.),
+            Series::from(vec![1., 2., 3., 2., 4.]),
+        )
+        .add_data(
+            Series::from(years).set_range(2018., 2022.),
+            Series::from(vec![2., 1., 1., 3., 2.]),
+        );
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Stacked area chart example"</h1>
+        <StackedAreaChart chart=chart/>
+      </div>
+    }
+}
@@ -0,0 +1,25 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let chart = Cartesian::new(
+        Series::from(vec![50, 70, 90, 110, 130]).set_range(40., 140.),
+        Series::from(vec![7, 9, 8, 12, 14]).set_range(6., 16.),
+    )
+    .set_view(820, 620, 3, 100, 100, 20);
+    let sizes = Series::from(vec![1., 4., 9., 2., 6.]);
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Bubble chart example"</h1>
+        // color, shift_degrees, r_min and r_max are options
+        <BubbleChart chart=chart sizes=sizes shift_degrees=40./>
+      </div>
+    }
+}
@@ -0,0 +1,27 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    // The periods and the close prices
+    let chart = Cartesian::new(
+        Series::from(vec!["Mon", "Tue", "Wed", "Thu", "Fri"]),
+        Series::from(vec![34., 36., 33., 33., 38.]),
+    )
+    .set_view(820, 620, 3, 50, 50, 20);
+    let open = Series::from(vec![30., 34., 36., 33., 33.]);
+    let high = Series::from(vec![35., 38., 37., 35., 39.]);
+    let low = Series::from(vec![29., 33., 31., 31., 32.]);
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Candlestick chart example"</h1>
+        <CandlestickChart chart=chart open=open high=high low=low/>
+      </div>
+    }
+}
@@ -0,0 +1,33 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let months = vec!["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+    let chart = Cartesian::new(
+        Series::from(months.clone()),
+        Series::from(vec![120., 135., 128., 160., 172., 190.]),
+    )
+    .set_view(820, 620, 3, 50, 60, 20);
+    let chart_line = Cartesian::new(
+        Series::from(months),
+        Series::from(vec![4., 12.5, -5.2, 25., 7.5, 10.5]),
+    );
+
+    view! {
+        <div class="mx-auto p-8">
+            <h1>"Combo chart example"</h1>
+            <ComboChart
+                chart=chart
+                chart_line=chart_line
+                y_title="k$"
+                y_title_right="%"
+            />
+        </div>
+    }
+}
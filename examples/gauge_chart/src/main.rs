@@ -0,0 +1,24 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let thresholds = vec![
+        (0., Color::from("#2ca02c")),
+        (60., Color::from("#ff7f0e")),
+        (85., Color::from("#d62728")),
+    ];
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Gauge chart example"</h1>
+        // min, max, color, thresholds, width and height are options
+        <GaugeChart value=72. thresholds=thresholds label="CPU"/>
+      </div>
+    }
+}
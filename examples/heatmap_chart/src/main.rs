@@ -0,0 +1,29 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    // The labels of the columns and of the rows
+    let chart = Cartesian::new(
+        Series::from(vec!["Mon", "Tue", "Wed", "Thu", "Fri"]),
+        Series::from(vec!["Morning", "Afternoon", "Evening"]),
+    )
+    .set_view(820, 420, 3, 50, 100, 20);
+    let values = vec![
+        vec![12., 18., 9., 14., 20.],
+        vec![25., 30., 22., f64::NAN, 28.],
+        vec![8., 6., 11., 7., 15.],
+    ];
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Heatmap chart example"</h1>
+        <HeatmapChart chart=chart values=values show_values=true/>
+      </div>
+    }
+}
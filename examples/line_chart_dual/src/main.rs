@@ -0,0 +1,28 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let hours = vec![0., 4., 8., 12., 16., 20.];
+    let chart = Cartesian::new(
+        Series::from(hours.clone()),
+        Series::from(vec![12., 11., 15., 22., 24., 17.]),
+    )
+    .set_view(820, 620, 3, 50, 60, 20);
+    let chart_right = Cartesian::new(
+        Series::from(hours),
+        Series::from(vec![85., 90., 70., 45., 40., 60.]),
+    );
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Dual axis line chart example"</h1>
+        <DualAxisLineChart chart=chart chart_right=chart_right y_title="°C" y_title_right="%"/>
+      </div>
+    }
+}
@@ -0,0 +1,56 @@
+use leptos::{html::Div, *};
+use leptos_chart::*;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{ResizeObserver, ResizeObserverEntry};
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    // The width of the container, measured on every resize
+    let container = create_node_ref::<Div>();
+    let (width, set_width) = create_signal(820.);
+    container.on_load(move |div| {
+        let on_resize = Closure::<dyn Fn(js_sys::Array)>::new(move |entries: js_sys::Array| {
+            if let Ok(entry) = entries.get(0).dyn_into::<ResizeObserverEntry>() {
+                set_width.set(entry.content_rect().width());
+            }
+        });
+        let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref()).unwrap();
+        observer.observe(&div);
+        // The observer lives as long as the page
+        on_resize.forget();
+    });
+
+    // The view of the chart follows the width, with a 4:3 aspect ratio
+    let chart = Signal::derive(move || {
+        let width = width.get().max(320.) as u64;
+        Cartesian::new(
+            Series::from(vec![0., 1.0, 2., 3., 4., 5.]),
+            Series::from(vec![1.0, 3.0, 2., 5., 4., 6.]),
+        )
+        .set_view(width, width * 3 / 4, 3, 100, 100, 20)
+    });
+
+    // The rendered <svg>, measured on demand
+    let svg = create_node_ref::<svg::Svg>();
+    let log_size = move |_| {
+        if let Some(svg) = svg.get() {
+            let bounds = svg.get_bounding_client_rect();
+            log::info!("Chart of {}x{} pixels", bounds.width(), bounds.height());
+        }
+    };
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Resizable line chart example"</h1>
+        <button on:click=log_size>"Log the size of the chart"</button>
+        <div node_ref=container style="width:80%">
+          <LineChartReactive chart=chart node_ref=svg/>
+        </div>
+      </div>
+    }
+}
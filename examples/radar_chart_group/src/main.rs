@@ -0,0 +1,30 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let skills = vec!["Reading", "Writing", "Listening", "Speaking", "React"];
+    let chart = CartesianGroup::new()
+        .set_view(740, 540, 3, 0, 0, 20)
+        .add_data(
+            Series::from(skills.clone()),
+            Series::from(vec![85.0, 55.0, 45., 60., 40.]),
+        )
+        .add_data(
+            Series::from(skills),
+            Series::from(vec![40.0, 75.0, 65., 30., 90.]),
+        );
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Radar chart group example"</h1>
+        // color, shift_degrees and series_names are options
+        <RadarChartGroup chart=chart legend=true/>
+      </div>
+    }
+}
@@ -0,0 +1,26 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let visits = Series::from(vec![3., 5., 4., 8., 6., 9., 12.]);
+    let errors = Series::from(vec![7., 6., 8., 4., 5., 2., 1.]);
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Sparkline example"</h1>
+        // width, height, color, stroke_width, kind and end_point are options
+        <p>"Visits " <Sparkline data=visits end_point=true/> " 12"</p>
+        <p>
+          "Errors "
+          <Sparkline data=errors color=Color::from("#d62728") kind=LineKind::Monotone/>
+          " 1"
+        </p>
+      </div>
+    }
+}
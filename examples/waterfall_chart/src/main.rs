@@ -0,0 +1,23 @@
+use leptos::*;
+use leptos_chart::*;
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::default());
+    leptos::mount_to_body(|| leptos::view! { <App/> })
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let chart = Cartesian::new(
+        Series::from(vec!["Sales", "Services", "Costs", "Taxes"]),
+        Series::from(vec![420., 210., -300., -90.]),
+    )
+    .set_view(820, 620, 3, 50, 50, 20);
+
+    view! {
+      <div class="mx-auto p-8">
+        <h1>"Waterfall chart example"</h1>
+        <WaterfallChart chart=chart total=true/>
+      </div>
+    }
+}
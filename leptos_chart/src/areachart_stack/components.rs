@@ -0,0 +1,282 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        check_group, class_name, clip_path, error_message, report_error, same_series, series_color,
+        theme_axis_color, ChartError, ChartTheme, DebugRegion, EmptyMessage, SvgChart, TickDir,
+    },
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{color::Color, coord, series::Series};
+
+/// Component StackedAreaChart for leptos
+///
+/// Each series is drawn as a filled band on top of the previous ones:
+/// its lower edge is the cumulative sum of the series below, its upper edge adds its values.
+/// The upper edge of the topmost band is the total.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["StackedAreaChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let years = vec![2018., 2019., 2020., 2021., 2022.];
+///     let chart = CartesianGroup::new()
+///         .set_view(840, 640, 3, 50, 50, 20)
+///         .add_data(
+///             Series::from(years.clone()).set_range(2018., 2022.),
+///             Series::from(vec![3., 4., 2., 5., 6.]),
+///         )
+///         .add_data(
+///             Series::from(years).set_range(2018., 2022.),
+///             Series::from(vec![1., 2., 3., 2., 4.]),
+///         );
+///
+///     view!{
+///         // color and shift_degrees are options
+///         <StackedAreaChart chart=chart />
+///     }
+/// }
+/// ```
+/// ## Set view for StackedAreaChart
+/// ```ignore
+///     ...
+///     .set_view(820, 620, 3, 100, 100, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis
+/// - `margin` : Margin for actual chart
+///
+/// ## About position_axes
+///
+/// - Top Left: 0
+/// - Top Right: 1
+/// - Bottom Right: 2
+/// - Bottom Left: 3
+///
+/// ## About the x series
+///
+/// The series of the group share the same x series, the bands are stacked per x value.
+/// Nothing is drawn and an error is logged when an x series differs from the first one.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font. The series take the
+/// colors of its palette instead of shifting the hue of `color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn StackedAreaChart(
+    chart: coord::CartesianGroup,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(default = 70.)] shift_degrees: f32,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Stacked area chart of {} series with {} points per series",
+        chart.get_data().len(),
+        chart.get_data().first().map_or(0, |(x, _)| x.get_count())
+    );
+    let axis_color = theme_axis_color(None, &theme);
+    let data = chart.get_data();
+    let checked = check_group(&chart).and_then(|_| {
+        match data
+            .iter()
+            .position(|(series_x, _)| !same_series(series_x, &data[0].0))
+        {
+            Some(index) => Err(ChartError::MismatchedSeries(index)),
+            None => Ok(()),
+        }
+    });
+    if let Err(error) = checked {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For Chart
+    let rec_chart = cview.get_rec_chart();
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For x-axis
+    let rec_xa = cview.get_rec_x_axis();
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+    let series_x = data[0].0.clone();
+    let axes_x = series_x.gen_axes();
+
+    // For y-axis
+    let rec_ya = cview.get_rec_y_axis();
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+
+    // For chart: the lower and upper edges of the bands, the running totals per x value
+    let xsticks = series_x.to_stick();
+    let mut totals = vec![0.; xsticks.len()];
+    let bands = data
+        .iter()
+        .map(|(_, series_y)| {
+            let lower = totals.clone();
+            for (total, stick) in totals.iter_mut().zip(series_y.to_stick()) {
+                *total += stick.value;
+            }
+            (lower, totals.clone())
+        })
+        .collect::<Vec<_>>();
+
+    // The axis of values covers zero and the cumulative sums
+    let series_y = Series::from(
+        bands
+            .iter()
+            .flat_map(|(_, upper)| upper.clone())
+            .chain([0.])
+            .collect::<Vec<_>>(),
+    );
+    let axes_y = series_y.gen_axes();
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme.clone()
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
+          // For draw region of chart
+
+          <DebugRegion vector=rec_chart.get_vector()/>
+
+          {
+              let vector = rec_chart.get_vector();
+              let xs = xsticks
+                  .iter()
+                  .map(|stick| series_x.scale(stick.value) * vector.get_x())
+                  .collect::<Vec<_>>();
+              bands
+                  .into_iter()
+                  .enumerate()
+                  .map(|(index, (lower, upper))| {
+                      let color = series_color(&theme, &color, shift_degrees, index);
+                      // The upper edge forward, then the lower edge backward
+                      let points = xs
+                          .iter()
+                          .zip(upper)
+                          .chain(xs.iter().zip(lower).rev())
+                          .map(|(x, value)| {
+                              format!("{:.0},{:.0}", x, series_y.scale(value) * vector.get_y())
+                          })
+                          .collect::<Vec<_>>();
+                      let path = format!("M {} Z", points.join(" L "));
+                      view! {
+                        <path
+                          class=class_name("band")
+                          d=path
+                          stroke=color.to_string_hex()
+                          fill=color.to_string_hex()
+                          fill-opacity=0.7
+                        ></path>
+                      }
+                  })
+                  .collect::<Vec<_>>()
+          }
+
+        </g>
+      </SvgChart>
+    }
+}
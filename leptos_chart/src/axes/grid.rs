@@ -0,0 +1,41 @@
+use crate::core::class_name;
+use leptos::{component, view, IntoView};
+use theta_chart::{
+    color::Color,
+    coord::{Axes, Rec},
+};
+
+// Draw the grid lines of the chart region at the sticks of the axes
+#[allow(non_snake_case)]
+#[component]
+pub fn Grid(
+    region: Rec,
+    axes_x: Axes,
+    axes_y: Axes,
+    #[prop(default = None)] color: Option<Color>,
+) -> impl IntoView {
+    let vector = region.get_vector();
+    // The lines are drawn with currentColor, overridden by the color attribute
+    let color = color.map(|color| color.to_string_hex());
+
+    view! {
+      <g class=class_name("grid") stroke="currentColor" stroke-opacity="0.1" color=color>
+        {axes_x
+            .sticks
+            .into_iter()
+            .map(|stick| {
+                let dx = stick.value * vector.get_x();
+                view! { <line x1=dx y1="0" x2=dx y2=vector.get_y()></line> }
+            })
+            .collect::<Vec<_>>()}
+        {axes_y
+            .sticks
+            .into_iter()
+            .map(|stick| {
+                let dy = stick.value * vector.get_y();
+                view! { <line x1="0" y1=dy x2=vector.get_x() y2=dy></line> }
+            })
+            .collect::<Vec<_>>()}
+      </g>
+    }
+}
@@ -3,3 +3,62 @@ pub(crate) use self::xaxis::XAxis;
 
 mod yaxis;
 pub(crate) use self::yaxis::YAxis;
+
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+mod grid;
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+pub(crate) use self::grid::Grid;
+
+use leptos::{Callable, Callback};
+
+// Format the label of a numeric stick, the other labels (e.g. categories) are unchanged
+pub(crate) fn format_label(label: String, format: Option<Callback<f64, String>>) -> String {
+    match (format, label.parse::<f64>()) {
+        (Some(format), Ok(value)) => format.call(value),
+        _ => label,
+    }
+}
+
+// Ellipsis ending a shortened label
+const ELLIPSIS: char = '\u{2026}';
+
+// Truncate a text to max_chars characters, the last one an ellipsis when it is shortened
+fn truncate_label(text: &str, max_chars: Option<usize>) -> String {
+    match max_chars {
+        Some(max_chars) if text.chars().count() > max_chars => {
+            let mut text = text
+                .chars()
+                .take(max_chars.saturating_sub(1))
+                .collect::<String>();
+            text.push(ELLIPSIS);
+            text
+        }
+        _ => text.to_string(),
+    }
+}
+
+// Lines of a label, at most max_chars characters each: one line, or two lines split at
+// a space when wrap is set, the last one truncated
+pub(crate) fn label_lines(label: &str, max_chars: Option<usize>, wrap: bool) -> Vec<String> {
+    let fits = max_chars.map_or(true, |max_chars| label.chars().count() <= max_chars);
+    if !wrap || (fits && max_chars.is_some()) {
+        return vec![truncate_label(label, max_chars)];
+    }
+
+    // The first line ends at the last space which fits in it, or near the middle
+    let words = label.split_whitespace().collect::<Vec<_>>();
+    let limit = max_chars.unwrap_or(label.chars().count().div_ceil(2));
+    let mut split = 1;
+    let mut length = words.first().map_or(0, |word| word.chars().count());
+    while split < words.len() && length + 1 + words[split].chars().count() <= limit {
+        length += 1 + words[split].chars().count();
+        split += 1;
+    }
+    if split >= words.len() {
+        return vec![truncate_label(&words.join(" "), max_chars)];
+    }
+    vec![
+        truncate_label(&words[..split].join(" "), max_chars),
+        truncate_label(&words[split..].join(" "), max_chars),
+    ]
+}
@@ -1,11 +1,30 @@
-use leptos::{component, view, IntoView};
-use theta_chart::coord::{Axes, Rec};
+use leptos::{component, view, Callback, CollectView, IntoView};
+use theta_chart::{
+    color::Color,
+    coord::{Axes, Rec},
+};
 
-use crate::core::REM;
+use super::{format_label, label_lines};
+use crate::core::{class_name, DebugRegion, Dir, TickDir, REM};
 
 #[allow(non_snake_case)]
 #[component]
-pub fn XAxis(region: Rec, axes: Axes) -> impl IntoView {
+pub fn XAxis(
+    region: Rec,
+    axes: Axes,
+    #[prop(default = None)] title: Option<String>,
+    #[prop(default = None)] color: Option<Color>,
+    #[prop(default = None)] format: Option<Callback<f64, String>>,
+    #[prop(default = 0.)] rotation: f32,
+    #[prop(default = Dir::Ltr)] dir: Dir,
+    #[prop(default = None)] max_label_chars: Option<usize>,
+    #[prop(default = false)] wrap_labels: bool,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+) -> impl IntoView {
+    // The lines and the texts are drawn with currentColor, overridden by the color attribute
+    let color = color.map(|color| color.to_string_hex());
+
     let vector = region.get_vector();
     let mut mark_origin_y = REM;
     let mut baseline = "text-before-edge";
@@ -16,6 +35,10 @@ pub fn XAxis(region: Rec, axes: Axes) -> impl IntoView {
         mark_origin_y *= -1.;
         baseline = "text-after-edge";
     }
+    // For ticks: their ends and the labels, away from the chart like the region
+    let sign = mark_origin_y.signum();
+    let tick_end = tick_dir.tick_end(tick_size, sign);
+    let label_y = tick_dir.label_offset(tick_size, sign);
 
     if axes.style == "time-month".to_string() {
         style = "writing-mode: tb;";
@@ -23,35 +46,35 @@ pub fn XAxis(region: Rec, axes: Axes) -> impl IntoView {
         text_anchor = "";
     }
 
+    // Rotated labels end at their sticks, away from the chart, centered on their baselines
+    let (rotated_anchor, rotated_baseline) = if rotation == 0. {
+        (None, None)
+    } else if (rotation < 0.) == (sign > 0.) {
+        (Some("end"), Some("middle"))
+    } else {
+        (Some("start"), Some("middle"))
+    };
+    // The first of two lines moves away from the axis on top, or up to center rotated labels
+    let first_dy = if rotation != 0. {
+        "-0.55em"
+    } else if vector.get_y() < 0. {
+        "-1.1em"
+    } else {
+        "0"
+    };
+
     view! {
-      {#[cfg(feature = "debug")]
-      {
-          let path = format!(
-              "M {},{} l {},{} l {},{} l {},{} Z",
-              0,
-              0,
-              vector.get_x(),
-              0,
-              0,
-              vector.get_y(),
-              -vector.get_x(),
-              0,
-          );
-          view! {
-            <circle id="originX" cx="0" cy="0" r="3"></circle>
-            <line
-              x1="0"
-              y1="0"
-              x2=vector.get_x()
-              y2=vector.get_y()
-              style="stroke:#ff000033;stroke-width:1"
-            ></line>
-            <path id="regionX" d=path fill="#ff000033"></path>
-          }
-      }}
+      <DebugRegion vector=vector.clone() color=Some("#ff0000") id="X"/>
 
       // Draw x-axis
-      <g class="stick" dominant-baseline=baseline text-anchor=text_anchor stroke="currentColor">
+      <g
+        class=class_name("stick")
+        dominant-baseline=baseline
+        text-anchor=text_anchor
+        direction=dir.attribute()
+        stroke="currentColor"
+        color=color.clone()
+      >
         <line x1="0" y1="0" x2=vector.get_x() y2="0"></line>
         <line x1="0" y1="0" x2="0" y2=mark_origin_y></line>
 
@@ -60,15 +83,72 @@ pub fn XAxis(region: Rec, axes: Axes) -> impl IntoView {
             .into_iter()
             .map(|stick| {
                 let dx = stick.value * vector.get_x();
+                let transform = (rotation != 0.)
+                    .then(|| format!("rotate({},{},{})", rotation, dx, label_y));
+                // Shortened labels show the full label on hover
+                let label = format_label(stick.label, format);
+                let lines = label_lines(&label, max_label_chars, wrap_labels);
+                let full_label = (lines.len() > 1 || lines[0] != label)
+                    .then(|| view! { <title>{label.clone()}</title> });
+                let text = if lines.len() > 1 {
+                    lines
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, line)| {
+                            let dy = if index == 0 { first_dy } else { "1.1em" };
+                            view! {
+                              <tspan x=dx dy=dy>
+                                {line}
+                              </tspan>
+                            }
+                        })
+                        .collect_view()
+                } else {
+                    lines.concat().into_view()
+                };
                 view! {
-                  <line x1=dx y1="0" x2=dx y2=mark_origin_y / 2.></line>
-                  <text y=mark_origin_y x=dx style=style fill="currentColor" stroke="none">
-                    {stick.label}
+                  <line x1=dx y1="0" x2=dx y2=tick_end></line>
+                  <text
+                    y=label_y
+                    x=dx
+                    style=style
+                    transform=transform
+                    text-anchor=rotated_anchor.map(|anchor| dir.anchor(anchor))
+                    dominant-baseline=rotated_baseline
+                    fill="currentColor"
+                    stroke="none"
+                  >
+                    {full_label}
+                    {text}
                   </text>
                 }
             })
             .collect::<Vec<_>>()}
 
       </g>
+
+      // Draw title at the outer edge of the region
+      {title
+          .map(|title| {
+              let baseline = if vector.get_y() < 0. {
+                  "text-before-edge"
+              } else {
+                  "text-after-edge"
+              };
+              view! {
+                <text
+                  class=class_name("axis-title")
+                  color=color
+                  x=vector.get_x() / 2.
+                  y=vector.get_y()
+                  dominant-baseline=baseline
+                  text-anchor="middle"
+                  direction=dir.attribute()
+                  fill="currentColor"
+                >
+                  {title}
+                </text>
+              }
+          })}
     }
 }
@@ -1,11 +1,27 @@
-use leptos::{component, view, IntoView};
-use theta_chart::coord::{Axes, Rec};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{
+    color::Color,
+    coord::{Axes, Rec},
+};
 
-use crate::core::REM;
+use super::format_label;
+use crate::core::{class_name, DebugRegion, Dir, TickDir, REM};
 
 #[allow(non_snake_case)]
 #[component]
-pub fn YAxis(region: Rec, axes: Axes) -> impl IntoView {
+pub fn YAxis(
+    region: Rec,
+    axes: Axes,
+    #[prop(default = None)] title: Option<String>,
+    #[prop(default = None)] color: Option<Color>,
+    #[prop(default = None)] format: Option<Callback<f64, String>>,
+    #[prop(default = Dir::Ltr)] dir: Dir,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+) -> impl IntoView {
+    // The lines and the texts are drawn with currentColor, overridden by the color attribute
+    let color = color.map(|color| color.to_string_hex());
+
     let vector = region.get_vector();
     let mut mark_origin_x = REM;
     let mut text_anchor = "start";
@@ -14,36 +30,23 @@ pub fn YAxis(region: Rec, axes: Axes) -> impl IntoView {
         mark_origin_x *= -1.;
         text_anchor = "end";
     }
+    // For ticks: their ends and the labels, away from the chart like the region
+    let sign = mark_origin_x.signum();
+    let tick_end = tick_dir.tick_end(tick_size, sign);
+    let label_x = tick_dir.label_offset(tick_size, sign);
 
     view! {
-      {#[cfg(feature = "debug")]
-      {
-          let path = format!(
-              "M {},{} l {},{} l {},{} l {},{} Z",
-              0,
-              0,
-              vector.get_x(),
-              0,
-              0,
-              vector.get_y(),
-              -vector.get_x(),
-              0,
-          );
-          view! {
-            <circle id="originY" cx="0" cy="0" r="3"></circle>
-            <line
-              x1="0"
-              y1="0"
-              x2=vector.get_x()
-              y2=vector.get_y()
-              style="stroke:#0000ff33;stroke-width:2"
-            ></line>
-            <path id="regionY" d=path fill="#0000ff33"></path>
-          }
-      }}
+      <DebugRegion vector=vector.clone() color=Some("#0000ff") id="Y"/>
 
       // Draw y-axis
-      <g class="stick" dominant-baseline="middle" text-anchor=text_anchor stroke="currentColor">
+      <g
+        class=class_name("stick")
+        dominant-baseline="middle"
+        text-anchor=dir.anchor(text_anchor)
+        direction=dir.attribute()
+        stroke="currentColor"
+        color=color.clone()
+      >
         <line x1="0" y1="0" x2="0" y2=vector.get_y()></line>
         <line x1="0" y1="0" x2=mark_origin_x y2="0"></line>
 
@@ -53,14 +56,40 @@ pub fn YAxis(region: Rec, axes: Axes) -> impl IntoView {
             .map(|stick| {
                 let dy = stick.value * vector.get_y();
                 view! {
-                  <line x1="0" y1=dy x2=mark_origin_x / 2. y2=dy></line>
-                  <text y=dy x=mark_origin_x fill="currentColor" stroke="none">
-                    {stick.label}
+                  <line x1="0" y1=dy x2=tick_end y2=dy></line>
+                  <text y=dy x=label_x fill="currentColor" stroke="none">
+                    {format_label(stick.label, format)}
                   </text>
                 }
             })
             .collect::<Vec<_>>()}
 
       </g>
+
+      // Draw title rotated at the outer edge of the region
+      {title
+          .map(|title| {
+              let baseline = if vector.get_x() < 0. {
+                  "text-before-edge"
+              } else {
+                  "text-after-edge"
+              };
+              let (x, y) = (vector.get_x(), vector.get_y() / 2.);
+              view! {
+                <text
+                  class=class_name("axis-title")
+                  color=color
+                  x=x
+                  y=y
+                  transform=format!("rotate(-90,{},{})", x, y)
+                  dominant-baseline=baseline
+                  text-anchor="middle"
+                  direction=dir.attribute()
+                  fill="currentColor"
+                >
+                  {title}
+                </text>
+              }
+          })}
     }
 }
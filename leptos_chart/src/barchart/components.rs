@@ -1,12 +1,19 @@
 use crate::{
     axes::{XAxis, YAxis},
-    core::SvgChart,
+    core::{
+        check_lengths, check_series, class_name, clip_path, error_message, report_error,
+        sort_labels, theme_axis_color, ChartError, ChartTheme, DebugRegion, EmptyMessage, Sort,
+        SvgChart, TickDir,
+    },
 };
-use leptos::{component, view, IntoView};
+use leptos::{component, view, Callback, IntoView};
 use theta_chart::{color::Color, coord, series::Series};
 
 /// Component BarChart for leptos
 ///
+/// One bar per category of a single series, filling most (90%) of the slot of its category.
+/// Also available as `ColumnChart`, see `BarChartGroup` for several series.
+///
 /// # Examples
 ///
 /// ## Cargo.toml
@@ -35,7 +42,7 @@ use theta_chart::{color::Color, coord, series::Series};
 ///
 ///     view!{
 ///         // color is option
-///         <BarChart data=data color=color />
+///         <BarChart chart=chart_v color=color />
 ///     }
 /// }
 /// ```
@@ -63,13 +70,80 @@ use theta_chart::{color::Color, coord, series::Series};
 /// - Bottom Right: 2
 /// - Bottom Left: 3
 ///
+/// ## About sort
+///
+/// - `Sort::None` (default): the bars in the order of the data
+/// - `Sort::Ascending`, `Sort::Descending`: the bars ordered by their values,
+///   with the labels of the axis, e.g. for a ranking
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
 #[allow(non_snake_case)]
+#[doc(alias = "ColumnChart")]
 #[component]
 pub fn BarChart(
     chart: coord::Cartesian,
     #[prop(default = Color::default())] color: Color,
+    #[prop(default = Sort::None)] sort: Sort,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
 ) -> impl IntoView {
     let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!("Bar chart of {} bars", chart.get_ax().get_count());
+    if let Err(error) = check_series(&chart.get_ax(), &chart.get_ay())
+        .and_then(|_| check_lengths(&chart.get_ax(), &chart.get_ay()))
+    {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+    let axis_color = theme_axis_color(None, &theme);
 
     // For Chart
     let rec_chart = cview.get_rec_chart();
@@ -86,7 +160,8 @@ pub fn BarChart(
         rec_xa.get_origin().get_x(),
         rec_xa.get_origin().get_y()
     );
-    let series_x = chart.get_ax();
+    // For sort: the labels ordered by the values, with either series of labels
+    let series_x = sort_labels(&chart.get_ax(), &[(chart.get_ax(), chart.get_ay())], sort);
     let axes_x = series_x.gen_axes();
 
     // For y-axis
@@ -96,14 +171,14 @@ pub fn BarChart(
         rec_ya.get_origin().get_x(),
         rec_ya.get_origin().get_y()
     );
-    let series_y = chart.get_ay();
+    let series_y = sort_labels(&chart.get_ay(), &[(chart.get_ay(), chart.get_ax())], sort);
     let axes_y = series_y.gen_axes();
 
     // For chart
-    let xseries = chart.get_ax();
-    let yseries = chart.get_ay();
-    let xsticks = xseries.to_stick();
-    let ysticks = yseries.to_stick();
+    let xseries = series_x;
+    let yseries = series_y;
+    let xsticks = chart.get_ax().to_stick();
+    let ysticks = chart.get_ay().to_stick();
 
     let mut x_is_label = true;
     match xseries {
@@ -112,44 +187,40 @@ pub fn BarChart(
     }
 
     view! {
-      <SvgChart cview=cview>
-        <g class="axes">
-          <g class="x-axis" transform=translate_xa>
-            <XAxis region=rec_xa axes=axes_x/>
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
-          <g class="y-axis" transform=translate_ya>
-            <YAxis region=rec_ya axes=axes_y/>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
         </g>
-        <g class="inner-chart" transform=translate_chart>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
           // For draw region of chart
 
-          {#[cfg(feature = "debug")]
-          {
-              let vector = rec_chart.get_vector();
-              let path = format!(
-                  "M {},{} l {},{} l {},{} l {},{} Z",
-                  0,
-                  0,
-                  vector.get_x(),
-                  0,
-                  0,
-                  vector.get_y(),
-                  -vector.get_x(),
-                  0,
-              );
-              view! {
-                <circle id="origin" cx="0" cy="0" r="3"></circle>
-                <line
-                  x1="0"
-                  y1="0"
-                  x2=vector.get_x()
-                  y2=vector.get_y()
-                  style="stroke:#00ff0033;stroke-width:2"
-                ></line>
-                <path id="region" d=path fill="#00ff0033"></path>
-              }
-          }}
+          <DebugRegion vector=rec_chart.get_vector()/>
 
           {
               let vector = rec_chart.get_vector();
@@ -164,9 +235,19 @@ pub fn BarChart(
                       .into_iter()
                       .enumerate()
                       .map(|(index, data)| {
-                          let x: f64 = xseries.scale(data.value + 0.5) * vector.get_x();
+                          let position = xseries.scale_index(data.label) as f64;
+                          let x: f64 = xseries.scale(position + 0.5) * vector.get_x();
                           let y: f64 = yseries.scale(ysticks[index].value) * vector.get_y();
-                          view! { <line x1=x y1="0" x2=x y2=y style=style.clone()></line> }
+                          view! {
+                            <line
+                              class=class_name("bar")
+                              x1=x
+                              y1="0"
+                              x2=x
+                              y2=y
+                              style=style.clone()
+                            ></line>
+                          }
                       })
                       .collect::<Vec<_>>()
               } else {
@@ -181,8 +262,18 @@ pub fn BarChart(
                       .enumerate()
                       .map(|(index, data)| {
                           let x: f64 = xseries.scale(data.value) * vector.get_x();
-                          let y: f64 = yseries.scale(ysticks[index].value + 0.5) * vector.get_y();
-                          view! { <line x1="0" y1=y x2=x y2=y style=style.clone()></line> }
+                          let position = yseries.scale_index(ysticks[index].label.clone()) as f64;
+                          let y: f64 = yseries.scale(position + 0.5) * vector.get_y();
+                          view! {
+                            <line
+                              class=class_name("bar")
+                              x1="0"
+                              y1=y
+                              x2=x
+                              y2=y
+                              style=style.clone()
+                            ></line>
+                          }
                       })
                       .collect::<Vec<_>>()
               }
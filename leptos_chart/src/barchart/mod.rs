@@ -1,4 +1,9 @@
 mod components;
 pub use self::components::BarChart;
 
+/// Same as [`BarChart`]: one column per category of a single series
+pub use self::components::BarChart as ColumnChart;
+
+// Also re-exported by the other charts of these coordinates, unused when one is enabled too
+#[allow(unused_imports)]
 pub use theta_chart::coord::Cartesian;
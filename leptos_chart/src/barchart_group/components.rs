@@ -1,10 +1,23 @@
 use crate::{
-    axes::{XAxis, YAxis},
-    core::SvgChart,
+    axes::{Grid, XAxis, YAxis},
+    core::{
+        check_group, class_name, clip_path, error_message, gradient_id, legend_layout,
+        linear_gradient, nice_series, pattern_fill, report_error, reverse_vertical, series_color,
+        sort_labels, theme_axis_color, theme_grid_color, ChartError, ChartTheme, DebugRegion,
+        EmptyMessage, Legend, LegendPos, Sort, SvgChart, TickDir, REM,
+    },
+};
+use leptos::{
+    component, create_node_ref, create_rw_signal,
+    ev::{self, MouseEvent},
+    svg::{self, Svg},
+    view, Callable, Callback, HtmlElement, IntoView, NodeRef, SignalGet, SignalSet,
 };
-use leptos::{component, view, IntoView};
 use theta_chart::{color::Color, coord, series::Series};
 
+// Largest gap of a group of bars or of a bar, as a fraction of its slot
+const MAX_PADDING: f64 = 0.9;
+
 /// Component LineChart for leptos
 ///
 /// # Examples
@@ -65,17 +78,345 @@ use theta_chart::{color::Color, coord, series::Series};
 /// - Bottom Right: 2
 /// - Bottom Left: 3
 ///
+/// ## About grid
+///
+/// Set `grid=true` to draw grid lines at the sticks of the axes.
+///
+/// ## About nice_axis
+///
+/// The value axis is extended to round bounds, with its sticks at steps of 1, 2 or 5 times
+/// a power of ten, see [`nice_range`](crate::nice_range).
+/// Set `nice_axis=false` to keep the bounds of the data or of the range set on the series.
+///
+/// ## About negative values
+///
+/// The bars start at the zero of the value axis, so negative bars go the other way.
+/// The bars are clamped to the region of the chart when the range of the axis is set.
+///
+/// ## About title
+///
+/// Optional `title` and `subtitle`, centered in a space reserved above the chart,
+/// with the font sizes `title_size` (default 20) and `subtitle_size` (default 14).
+///
+/// ## About clip
+///
+/// The data are clipped at the region of the chart, with a margin of 8 pixels for the
+/// markers at its edges, e.g. when a value is out of a fixed range. Set `clip=false`
+/// to draw them in full.
+///
+/// ## About class_prefix
+///
+/// Optional prefix of the classes of the elements, e.g. `class_prefix="myapp"` gives
+/// `myapp-chart`, `myapp-inner-chart`, `myapp-x-axis`, `myapp-bar`, ... to style
+/// several charts of a page with different stylesheets.
+///
+/// ## About background
+///
+/// Optional `background` of the whole SVG and `plot_background` of the region of the chart,
+/// drawn behind the axes and the data, e.g. to match a themed card. The SVG is transparent
+/// otherwise.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes, the texts and the grid, and the font, unless set by
+/// the props. The series take the colors of its palette instead of shifting the hue of
+/// `color`, in the legend too.
+///
+/// ## About empty_message
+///
+/// Optional message centered in the chart when it has no data or an empty series, e.g.
+/// `empty_message="No data"`, instead of a blank chart.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About animate
+///
+/// Set `animate=true` to grow the bars from the baseline when the chart is drawn,
+/// the duration is `animate_ms` (default 300 ms).
+///
+/// ## About show_values
+///
+/// Set `show_values=true` to write the value at the tip of each bar,
+/// formatted by the optional `value_format` (default: the number as is).
+///
+/// ```ignore
+///     fn percent(value: f64) -> String {
+///         format!("{:.1}%", value * 100.)
+///     }
+///     view!{
+///         <BarChartGroup chart=chart show_values=true value_format=percent />
+///     }
+/// ```
+///
+/// ## About axis_color
+///
+/// Optional color of the axis lines, ticks and labels, e.g. for a dark theme
+/// (default currentColor).
+///
+/// ## About x_tick_format and y_tick_format
+///
+/// Optional callbacks formatting the numeric labels of the horizontal and vertical axes,
+/// e.g. `Callback::new(|v: f64| format!("{:.0}%", v * 100.))`.
+///
+/// ## About x_label_rotation
+///
+/// Rotation in degrees of the labels of the horizontal axis, e.g. `x_label_rotation=-45.`
+/// for long category names which would overlap (default 0). The rotated labels end at
+/// their sticks.
+///
+/// ## About x_label_max_chars and x_label_wrap
+///
+/// Set `x_label_max_chars=Some(10)` to truncate the labels of the horizontal axis longer
+/// than 10 characters with an ellipsis, and `x_label_wrap=true` to wrap them on two lines
+/// split at a space, the second one truncated. The shortened labels show the full label
+/// on hover. They combine with `x_label_rotation`.
+///
+/// ## About y_reverse
+///
+/// Set `y_reverse=true` to reverse the vertical axis: with values on it, the larger values
+/// are at the bottom and the bars hang from the top; with labels on it, the first label is
+/// at the top, e.g. for a ranking.
+///
+/// ## About bar_radius
+///
+/// Set `bar_radius` to round the corners of the bars with this radius in pixels, e.g.
+/// `bar_radius=4.`, the bars are then drawn as `<rect>` (default 0: square corners).
+///
+/// ## About group_padding and bar_padding
+///
+/// The gaps between the groups of bars of the categories, `group_padding` (default 0.1),
+/// and between the bars of a group, `bar_padding` (default 0), as fractions of the slot of
+/// a group and of the slot of a bar, e.g. `group_padding=0.3 bar_padding=0.1` for an airy
+/// chart. They are kept from 0 to 0.9, a value out of these bounds is logged.
+///
+/// ## About bar_colors
+///
+/// Optional colors per stick (category), e.g. to highlight one bar among grey ones.
+/// They override the colors of the series, shifted by `shift_degrees`, which are kept
+/// for the sticks beyond the given colors and for the legend.
+///
+/// ```ignore
+///     let grey = Color::from("#aaaaaa");
+///     let bar_colors = vec![grey.clone(), Color::from("#ff0000"), grey];
+///     view!{
+///         <BarChartGroup chart=chart bar_colors=bar_colors />
+///     }
+/// ```
+///
+/// ## About gradient
+///
+/// Set `gradient=true` to fill the bars with a gradient along the values,
+/// from a lighter shade of their color at the origin of the chart to their color.
+///
+/// ## About patterns
+///
+/// Set `patterns=true` to fill the bars of each series with a distinct pattern of white
+/// marks over its color (diagonal lines, dots, crosshatch and horizontal lines, cycled by
+/// series), also in the legend. The series stay distinct without colors, e.g. for
+/// colorblind readers or a black and white print. The patterns override `gradient` and
+/// `bar_colors`.
+///
+/// ## About on_bar_click
+///
+/// Optional callback fired with `(group_index, stick_index)` when a bar is clicked.
+///
+/// ```ignore
+///     let on_bar_click = Callback::new(|(group, stick): (usize, usize)| {
+///         log::info!("clicked bar {} of group {}", stick, group);
+///     });
+///     view!{
+///         <BarChartGroup chart=chart on_bar_click=on_bar_click />
+///     }
+/// ```
+///
+/// ## About tooltips
+///
+/// Set `tooltips=true` to show the name of the series, the label and the value of the bar
+/// under the pointer, in a box next to it. The box stays in the region of the chart,
+/// on the left of the pointer near the right edge and below it near the top edge.
+///
+/// ## About legend
+///
+/// Set `legend=true` to draw a legend at the side `legend_pos` of the chart,
+/// with a name per series from `series_names` (default "Series 1", "Series 2", ...).
+/// Click an entry of the legend to hide or show its series, the axes stay fixed.
+///
+/// - `LegendPos::Right` (default), `LegendPos::Left`: the entries in a column
+/// - `LegendPos::Top`, `LegendPos::Bottom`: the entries in a row
+///
+/// The region of the chart shrinks to make room for the legend.
+/// The [`Legend`](crate::Legend) component can also be used standalone.
+///
+/// ## About hover_emphasis
+///
+/// Set `hover_emphasis=true` to dim the other series when the pointer is over one series,
+/// to focus on it in a busy chart. Each series also has the class `series-{index}`.
+///
+/// ## About sort
+///
+/// - `Sort::None` (default): the groups of bars in the order of the data
+/// - `Sort::Ascending`, `Sort::Descending`: the groups ordered by the sums of their values
+///   over the series, with the labels of the axis, e.g. for a top-N chart
+///
+/// ## About node_ref
+///
+/// Optional reference to the rendered `<svg>`, e.g. to save the chart with
+/// [`export_svg`](crate::export_svg) and [`download_svg`](crate::download_svg),
+/// or to measure it with `get_bounding_client_rect()`.
+///
+/// ## About responsive
+///
+/// Set `responsive=true` to scale the chart to the width of its container,
+/// keeping the aspect ratio of `set_view`, or `fixed_size=true` to give the chart the
+/// pixel size of `set_view`; else the `<svg>` only has a `viewBox`, sized by its CSS.
+/// To lay out the chart again at the size of its container instead, with texts of the same
+/// size, measure the container, e.g. with a `ResizeObserver`, and render the chart in a
+/// `move ||` closure giving the measured size to `set_view`, as `LineChartReactive` in the
+/// example `line_chart_resize`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead,
+/// or `empty_message` without data.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
 #[allow(non_snake_case)]
 #[component]
 pub fn BarChartGroup(
     chart: coord::CartesianGroup,
     #[prop(default = Color::default())] color: Color,
     #[prop(default = 70.)] shift_degrees: f32,
+    #[prop(default = Sort::None)] sort: Sort,
+    #[prop(optional)] on_bar_click: Option<Callback<(usize, usize)>>,
+    #[prop(default = false)] grid: bool,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+    #[prop(default = false)] responsive: bool,
+    #[prop(default = false)] fixed_size: bool,
+    #[prop(default = false)] legend: bool,
+    #[prop(default = LegendPos::Right)] legend_pos: LegendPos,
+    #[prop(optional, into)] series_names: Vec<String>,
+    #[prop(default = false)] hover_emphasis: bool,
+    #[prop(default = false)] tooltips: bool,
+    #[prop(optional)] axis_color: Option<Color>,
+    #[prop(default = false)] show_values: bool,
+    #[prop(optional)] value_format: Option<fn(f64) -> String>,
+    #[prop(default = false)] animate: bool,
+    #[prop(default = 300.0)] animate_ms: f64,
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] subtitle: Option<String>,
+    #[prop(default = 1.25 * REM)] title_size: f64,
+    #[prop(default = 0.875 * REM)] subtitle_size: f64,
+    #[prop(optional)] x_tick_format: Option<Callback<f64, String>>,
+    #[prop(default = 0.0)] x_label_rotation: f32,
+    #[prop(default = None)] x_label_max_chars: Option<usize>,
+    #[prop(default = false)] x_label_wrap: bool,
+    #[prop(default = false)] y_reverse: bool,
+    #[prop(default = true)] nice_axis: bool,
+    #[prop(optional)] y_tick_format: Option<Callback<f64, String>>,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional, into)] empty_message: Option<String>,
+    #[prop(optional)] background: Option<Color>,
+    #[prop(optional)] plot_background: Option<Color>,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional)] bar_colors: Option<Vec<Color>>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+    #[prop(default = false)] gradient: bool,
+    #[prop(default = 0.0)] bar_radius: f64,
+    #[prop(default = 0.1)] group_padding: f64,
+    #[prop(default = 0.0)] bar_padding: f64,
+    #[prop(default = false)] patterns: bool,
 ) -> impl IntoView {
     let cview = chart.get_view();
+    let axis_color = theme_axis_color(axis_color, &theme);
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Bar chart of {} series with {} bars per series",
+        chart.get_data().len(),
+        chart.get_data().first().map_or(0, |(x, _)| x.get_count())
+    );
+    if let Err(error) = check_group(&chart) {
+        report_error(&error, on_error);
+        // For empty_message: only without data, else the message of the error
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            node_ref=node_ref
+            responsive=responsive
+            fixed_size=fixed_size
+            title=title
+            subtitle=subtitle
+            title_size=title_size
+            subtitle_size=subtitle_size
+            aria_label=aria_label.clone()
+            background=background.clone()
+            plot_background=plot_background.clone()
+            theme=theme.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, empty_message)/>
+          </SvgChart>
+        };
+    }
+
+    // For legend: same colors as the bars, in a room taken at the side legend_pos
+    let legend_items = (0..chart.get_data().len())
+        .map(|index| {
+            let name = series_names
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| format!("Series {}", index + 1));
+            (name, series_color(&theme, &color, shift_degrees, index))
+        })
+        .collect::<Vec<_>>();
+    // For visibility of the series, toggled by a click on the legend
+    let visible = legend_items
+        .iter()
+        .map(|_| create_rw_signal(true))
+        .collect::<Vec<_>>();
+    let ((rec_chart, rec_xa, rec_ya), translate_legend) = legend_layout(
+        legend.then_some(legend_pos),
+        &legend_items,
+        (
+            cview.get_rec_chart(),
+            cview.get_rec_x_axis(),
+            cview.get_rec_y_axis(),
+        ),
+    );
+    let horizontal = matches!(legend_pos, LegendPos::Top | LegendPos::Bottom);
 
-    // For Chart
-    let rec_chart = cview.get_rec_chart();
+    // For Chart: from its top edge with y_reverse
+    let rec_chart = if y_reverse {
+        reverse_vertical(&rec_chart)
+    } else {
+        rec_chart
+    };
     let translate_chart = format!(
         "translate({},{})",
         rec_chart.get_origin().get_x(),
@@ -83,28 +424,41 @@ pub fn BarChartGroup(
     );
 
     // For x-axis
-    let rec_xa = cview.get_rec_x_axis();
     let translate_xa = format!(
         "translate({},{})",
         rec_xa.get_origin().get_x(),
         rec_xa.get_origin().get_y()
     );
-    let series_x_group = chart.get_ax_group();
-    let axes_x = series_x_group.gen_axes();
+    // For sort: the labels ordered by the sums of the values, with either series of labels
+    let data = chart.get_data();
+    let swapped = data
+        .iter()
+        .map(|(x, y)| (y.clone(), x.clone()))
+        .collect::<Vec<_>>();
+    let (series_x_group, axes_x) = nice_series(
+        &sort_labels(&chart.get_ax_group(), &data, sort),
+        None,
+        nice_axis,
+    );
 
     // For y-axis
-    let rec_ya = cview.get_rec_y_axis();
+    let rec_ya = if y_reverse {
+        reverse_vertical(&rec_ya)
+    } else {
+        rec_ya
+    };
     let translate_ya = format!(
         "translate({},{})",
         rec_ya.get_origin().get_x(),
         rec_ya.get_origin().get_y()
     );
-    let series_y_group = chart.get_ay_group();
-
-    let axes_y = series_y_group.gen_axes();
+    let (series_y_group, axes_y) = nice_series(
+        &sort_labels(&chart.get_ay_group(), &swapped, sort),
+        None,
+        nice_axis,
+    );
 
     // For chart
-    let data = chart.get_data();
     let mut xseries: Vec<Series> = vec![];
     let mut yseries: Vec<Series> = vec![];
     for tup in data {
@@ -112,6 +466,88 @@ pub fn BarChartGroup(
         yseries.push(tup.1);
     }
 
+    // For spacing: fractions of the slot of a group of bars and of the slot of a bar,
+    // below 1 for the bars to keep a width
+    let padding = |name: &str, padding: f64| {
+        if !(0. ..=MAX_PADDING).contains(&padding) {
+            let message = format!("The {} {} is out of 0 to {}", name, padding, MAX_PADDING);
+            report_error(&ChartError::OutOfRange(message), on_error);
+        }
+        if padding.is_nan() {
+            0.
+        } else {
+            padding.clamp(0., MAX_PADDING)
+        }
+    };
+    let group_padding = padding("group_padding", group_padding);
+    let bar_padding = padding("bar_padding", bar_padding);
+
+    // For emphasis of the hovered series, the others are dimmed
+    let highlighted = create_rw_signal::<Option<usize>>(None);
+
+    // For tooltip: pointer in the coordinates of the chart, measured on its region,
+    // and the text of the hovered bar
+    let hovered = create_rw_signal::<Option<(f64, f64, String)>>(None);
+    let region = create_node_ref::<svg::Rect>();
+    let (vector_x, vector_y) = (
+        rec_chart.get_vector().get_x(),
+        rec_chart.get_vector().get_y(),
+    );
+    let position = move |event: &MouseEvent| {
+        let bounds = region.get_untracked()?.get_bounding_client_rect();
+        if bounds.width() <= 0. || bounds.height() <= 0. {
+            return None;
+        }
+        let fraction_x = (event.client_x() as f64 - bounds.left()) / bounds.width();
+        let fraction_y = (event.client_y() as f64 - bounds.top()) / bounds.height();
+        Some((
+            vector_x.min(0.) + fraction_x * vector_x.abs(),
+            vector_y.min(0.) + fraction_y * vector_y.abs(),
+        ))
+    };
+    let on_hover = move |text: String| {
+        move |event: MouseEvent| {
+            if let Some((x, y)) = position(&event).filter(|_| tooltips) {
+                hovered.set(Some((x, y, text.clone())));
+            }
+        }
+    };
+    let names = legend_items
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+    // For value labels
+    let format_value = move |value: f64| value_format.map_or(value.to_string(), |f| f(value));
+
+    // For paint of a bar: the pattern of its series with `patterns=true`, else the color
+    // of its stick if given, else the one of its series, through their gradients with
+    // `gradient=true`
+    let gradient_prefix = gradient_id();
+    let paint = {
+        let bar_colors = bar_colors.clone();
+        let gradient_prefix = gradient_prefix.clone();
+        move |series: usize, stick: usize, color: &Color| {
+            let bar_color = bar_colors
+                .as_ref()
+                .and_then(|bar_colors| bar_colors.get(stick));
+            if patterns {
+                return format!("url(#{}-pattern-{})", gradient_prefix, series);
+            }
+            match (gradient, bar_color) {
+                (false, Some(bar_color)) => bar_color.to_string_hex(),
+                (false, None) => color.to_string_hex(),
+                (true, Some(_)) => format!("url(#{}-stick-{})", gradient_prefix, stick),
+                (true, None) => format!("url(#{}-series-{})", gradient_prefix, series),
+            }
+        }
+    };
+    let count_series = legend_items.len();
+    let legend_fills = (0..count_series)
+        .filter(|_| patterns)
+        .map(|index| format!("url(#{}-pattern-{})", gradient_prefix, index))
+        .collect::<Vec<_>>();
+
     let mut x_is_label = true;
     match xseries[0] {
         Series::Label(_) => (),
@@ -119,132 +555,558 @@ pub fn BarChartGroup(
     }
 
     view! {
-      <SvgChart cview=cview>
-        <g class="axes">
-          <g class="x-axis" transform=translate_xa>
-            <XAxis region=rec_xa axes=axes_x/>
+      <SvgChart
+        cview=cview
+        node_ref=node_ref
+        responsive=responsive
+        fixed_size=fixed_size
+        title=title
+        subtitle=subtitle
+        title_size=title_size
+        subtitle_size=subtitle_size
+        aria_label=aria_label
+        background=background
+        plot_background=plot_background
+        theme=theme.clone()
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        clip=clip
+        region=Some(rec_chart.clone())
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x.clone()
+              color=axis_color.clone()
+              format=x_tick_format
+              rotation=x_label_rotation
+              max_label_chars=x_label_max_chars
+              wrap_labels=x_label_wrap
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
-          <g class="y-axis" transform=translate_ya>
-            <YAxis region=rec_ya axes=axes_y/>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y.clone()
+              color=axis_color
+              format=y_tick_format
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
         </g>
-        <g class="inner-chart" transform=translate_chart>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
           // For draw region of chart
 
-          {#[cfg(feature = "debug")]
-          {
-              let vector = rec_chart.get_vector();
-              let path = format!(
-                  "M {},{} l {},{} l {},{} l {},{} Z",
-                  0,
-                  0,
-                  vector.get_x(),
-                  0,
-                  0,
-                  vector.get_y(),
-                  -vector.get_x(),
-                  0,
-              );
-              view! {
-                <circle id="origin" cx="0" cy="0" r="3"></circle>
-                <line
-                  x1="0"
-                  y1="0"
-                  x2=vector.get_x()
-                  y2=vector.get_y()
-                  style="stroke:#00ff0033;stroke-width:2"
-                ></line>
-                <path id="region" d=path fill="#00ff0033"></path>
-              }
-          }}
+          <DebugRegion vector=rec_chart.get_vector()/>
+
+          // For grid lines, drawn before the data to be behind it
+          {grid
+              .then(|| {
+                  view! {
+                    <Grid
+                      region=rec_chart.clone()
+                      axes_x=axes_x
+                      axes_y=axes_y
+                      color=theme_grid_color(&theme)
+                    />
+                  }
+              })}
+
+          {gradient
+              .then(|| {
+                  // Along the values, from the origin of the chart
+                  let vector = rec_chart.get_vector();
+                  let to = if x_is_label { (0., vector.get_y()) } else { (vector.get_x(), 0.) };
+                  let series = (0..count_series)
+                      .map(|index| {
+                          linear_gradient(
+                              format!("{}-series-{}", gradient_prefix, index),
+                              &series_color(&theme, &color, shift_degrees, index),
+                              (0., 0.),
+                              to,
+                          )
+                      })
+                      .collect::<Vec<_>>();
+                  let sticks = bar_colors
+                      .iter()
+                      .flatten()
+                      .enumerate()
+                      .map(|(stick, bar_color)| {
+                          linear_gradient(
+                              format!("{}-stick-{}", gradient_prefix, stick),
+                              bar_color,
+                              (0., 0.),
+                              to,
+                          )
+                      })
+                      .collect::<Vec<_>>();
+                  view! { <defs>{series} {sticks}</defs> }
+              })}
+
+          {patterns
+              .then(|| {
+                  let series = (0..count_series)
+                      .map(|index| {
+                          pattern_fill(
+                              format!("{}-pattern-{}", gradient_prefix, index),
+                              &series_color(&theme, &color, shift_degrees, index),
+                              index,
+                          )
+                      })
+                      .collect::<Vec<_>>();
+                  view! { <defs>{series}</defs> }
+              })}
 
           {
               let vector = rec_chart.get_vector();
               if x_is_label {
                   let len = xseries.len();
-                  let position = 0.9 / len as f64;
+                  let slot = (1. - group_padding) / len as f64;
+                  let width_bar = slot * (1. - bar_padding);
                   let len_group = series_x_group.get_count();
                   xseries
                       .into_iter()
                       .enumerate()
                       .map(|(index, series_x)| {
-                          let color = color.shift_hue_degrees_index(shift_degrees, index);
+                          let color = series_color(&theme, &color, shift_degrees, index);
                           let xstick = series_x.to_stick();
                           let ystick = yseries[index].to_stick();
-                          let width_col = series_x_group.scale(position) * vector.get_x();
-                          let style = format!(
-                              "stroke:{};stroke-width:{}",
-                              color.to_string_hex(),
-                              width_col.abs() as u64,
-                          );
+                          let width_col = series_x_group.scale(width_bar) * vector.get_x();
                           let interval = vector.get_x() / len_group as f64;
-                          xstick
+                          // Bars start at the scaled zero, negative values go the other way
+                          let zero = series_y_group.scale(0.).clamp(0., 1.) * vector.get_y();
+                          let shown = visible[index];
+                          let bars = xstick
                               .into_iter()
                               .enumerate()
                               .map(|(indexi, data)| {
                                   let label = data.label;
+                                  let style = format!(
+                                      "stroke:{};stroke-width:{}",
+                                      paint(index, indexi, &color),
+                                      width_col.abs() as u64,
+                                  );
                                   let x: f64 = ((series_x_group.scale_index(label.clone()) as f64
                                       / (len_group as f64)) as f64) * vector.get_x()
-                                      + (position * index as f64 + position / 2. + 0.05) * interval;
-                                  let y: f64 = series_y_group.scale(ystick[indexi].value)
-                                      * vector.get_y();
+                                      + (slot * index as f64 + slot / 2. + group_padding / 2.)
+                                          * interval;
+                                  let value = ystick[indexi].value;
+                                  let y: f64 =
+                                      series_y_group.scale(value).clamp(0., 1.) * vector.get_y();
+                                  // Value label just beyond the tip of the bar
+                                  let text = show_values
+                                      .then(|| {
+                                          let (dy, baseline) = if y < zero {
+                                              (-REM / 4., "text-after-edge")
+                                          } else {
+                                              (REM / 4., "text-before-edge")
+                                          };
+                                          view! {
+                                            <text
+                                              class=class_name("value")
+                                              x=x
+                                              y=y + dy
+                                              text-anchor="middle"
+                                              dominant-baseline=baseline
+                                            >
+                                              {format_value(value)}
+                                            </text>
+                                          }
+                                      });
+                                  let on_click = move |_| {
+                                      if let Some(on_bar_click) = on_bar_click {
+                                          on_bar_click.call((index, indexi));
+                                      }
+                                  };
+                                  let on_move = on_hover(format!(
+                                      "{}, {}: {}",
+                                      names[index],
+                                      label,
+                                      format_value(value)
+                                  ));
+                                  let bar = if bar_radius > 0. {
+                                      rounded_bar(
+                                          true,
+                                          (x, zero),
+                                          (x, y),
+                                          width_col.abs(),
+                                          bar_radius,
+                                          paint(index, indexi, &color),
+                                          animate.then_some(animate_ms),
+                                      )
+                                          .on(ev::click, on_click)
+                                          .on(ev::mousemove, on_move)
+                                          .on(ev::mouseleave, move |_| hovered.set(None))
+                                          .into_view()
+                                  } else {
+                                      view! {
+                                        <line
+                                          class=class_name("bar")
+                                          x1=x
+                                          y1=zero
+                                          x2=x
+                                          y2=y
+                                          style=style
+                                          on:click=on_click
+                                          on:mousemove=on_move
+                                          on:mouseleave=move |_| hovered.set(None)
+                                        >
+                                          {animate
+                                              .then(|| {
+                                                  view! {
+                                                    <animate
+                                                      attributeName="y2"
+                                                      from=zero
+                                                      to=y
+                                                      dur=format!("{}ms", animate_ms)
+                                                      fill="freeze"
+                                                    ></animate>
+                                                  }
+                                              })}
+                                        </line>
+                                      }
+                                          .into_view()
+                                  };
                                   view! {
-                                    // len as f64;
-
-                                    <line x1=x y1="0" x2=x y2=y style=style.clone()></line>
+                                    {bar}
+                                    {text}
                                   }
                               })
-                              .collect::<Vec<_>>()
+                              .collect::<Vec<_>>();
+                          let class = format!(
+                              "{} {}",
+                              class_name("series"),
+                              class_name(&format!("series-{}", index))
+                          );
+                          let dimmed = move || {
+                              highlighted.get().is_some_and(|other| other != index).then_some(0.3)
+                          };
+                          view! {
+                            <g
+                              class=class
+                              display=move || (!shown.get()).then_some("none")
+                              opacity=dimmed
+                              on:mouseenter=move |_| {
+                                  if hover_emphasis {
+                                      highlighted.set(Some(index));
+                                  }
+                              }
+                              on:mouseleave=move |_| highlighted.set(None)
+                            >
+                              {bars}
+                            </g>
+                          }
                       })
                       .collect::<Vec<_>>()
               } else {
                   let len = yseries.len();
-                  let position = 0.9 / len as f64;
+                  let slot = (1. - group_padding) / len as f64;
+                  let width_bar = slot * (1. - bar_padding);
                   let len_group = series_y_group.get_count();
                   yseries
                       .into_iter()
                       .enumerate()
                       .map(|(index, series_y)| {
-                          let color = color.shift_hue_degrees_index(shift_degrees, index);
+                          let color = series_color(&theme, &color, shift_degrees, index);
                           let xstick = xseries[index].to_stick();
                           let ystick = series_y.to_stick();
-                          let width_col = series_y_group.scale(position) * vector.get_y();
-                          let style = format!(
-                              "stroke:{};stroke-width:{}",
-                              color.to_string_hex(),
-                              width_col.abs() as u64,
-                          );
+                          let width_col = series_y_group.scale(width_bar) * vector.get_y();
                           let interval = vector.get_y() / len_group as f64;
-                          ystick
+                          // Bars start at the scaled zero, negative values go the other way
+                          let zero = series_x_group.scale(0.).clamp(0., 1.) * vector.get_x();
+                          let shown = visible[index];
+                          let bars = ystick
                               .into_iter()
                               .enumerate()
                               .map(|(indexi, data)| {
                                   let label = data.label;
-                                  let x: f64 = series_x_group.scale(xstick[indexi].value)
-                                      * vector.get_x();
+                                  let style = format!(
+                                      "stroke:{};stroke-width:{}",
+                                      paint(index, indexi, &color),
+                                      width_col.abs() as u64,
+                                  );
+                                  let value = xstick[indexi].value;
+                                  let x: f64 =
+                                      series_x_group.scale(value).clamp(0., 1.) * vector.get_x();
                                   let y: f64 = ((series_y_group.scale_index(label.clone()) as f64
                                       / (len_group as f64)) as f64) * vector.get_y()
-                                      + (position * index as f64 + position / 2. + 0.05) * interval;
+                                      + (slot * index as f64 + slot / 2. + group_padding / 2.)
+                                          * interval;
+                                  // Value label just beyond the tip of the bar
+                                  let text = show_values
+                                      .then(|| {
+                                          let (dx, anchor) = if x < zero {
+                                              (-REM / 4., "end")
+                                          } else {
+                                              (REM / 4., "start")
+                                          };
+                                          view! {
+                                            <text
+                                              class=class_name("value")
+                                              x=x + dx
+                                              y=y
+                                              text-anchor=anchor
+                                              dominant-baseline="middle"
+                                            >
+                                              {format_value(value)}
+                                            </text>
+                                          }
+                                      });
+                                  let on_click = move |_| {
+                                      if let Some(on_bar_click) = on_bar_click {
+                                          on_bar_click.call((index, indexi));
+                                      }
+                                  };
+                                  let on_move = on_hover(format!(
+                                      "{}, {}: {}",
+                                      names[index],
+                                      label,
+                                      format_value(value)
+                                  ));
+                                  let bar = if bar_radius > 0. {
+                                      rounded_bar(
+                                          false,
+                                          (zero, y),
+                                          (x, y),
+                                          width_col.abs(),
+                                          bar_radius,
+                                          paint(index, indexi, &color),
+                                          animate.then_some(animate_ms),
+                                      )
+                                          .on(ev::click, on_click)
+                                          .on(ev::mousemove, on_move)
+                                          .on(ev::mouseleave, move |_| hovered.set(None))
+                                          .into_view()
+                                  } else {
+                                      view! {
+                                        <line
+                                          class=class_name("bar")
+                                          x1=zero
+                                          y1=y
+                                          x2=x
+                                          y2=y
+                                          style=style
+                                          on:click=on_click
+                                          on:mousemove=on_move
+                                          on:mouseleave=move |_| hovered.set(None)
+                                        >
+                                          {animate
+                                              .then(|| {
+                                                  view! {
+                                                    <animate
+                                                      attributeName="x2"
+                                                      from=zero
+                                                      to=x
+                                                      dur=format!("{}ms", animate_ms)
+                                                      fill="freeze"
+                                                    ></animate>
+                                                  }
+                                              })}
+                                        </line>
+                                      }
+                                          .into_view()
+                                  };
                                   view! {
-                                    // len as f64;
-
-                                    // len as f64;
-
-                                    // len as f64;
-
-                                    // len as f64;
-
-                                    <line x1="0" y1=y x2=x y2=y style=style.clone()></line>
+                                    {bar}
+                                    {text}
                                   }
                               })
-                              .collect::<Vec<_>>()
+                              .collect::<Vec<_>>();
+                          let class = format!(
+                              "{} {}",
+                              class_name("series"),
+                              class_name(&format!("series-{}", index))
+                          );
+                          let dimmed = move || {
+                              highlighted.get().is_some_and(|other| other != index).then_some(0.3)
+                          };
+                          view! {
+                            <g
+                              class=class
+                              display=move || (!shown.get()).then_some("none")
+                              opacity=dimmed
+                              on:mouseenter=move |_| {
+                                  if hover_emphasis {
+                                      highlighted.set(Some(index));
+                                  }
+                              }
+                              on:mouseleave=move |_| highlighted.set(None)
+                            >
+                              {bars}
+                            </g>
+                          }
                       })
                       .collect::<Vec<_>>()
               }
           }
 
+          // For tooltip: the region measuring the pointer, and the box drawn above the bars
+          {tooltips
+              .then(|| {
+                  view! {
+                    <rect
+                      node_ref=region
+                      x=vector_x.min(0.)
+                      y=vector_y.min(0.)
+                      width=vector_x.abs()
+                      height=vector_y.abs()
+                      fill="none"
+                      pointer-events="none"
+                    ></rect>
+                  }
+              })}
+          {move || {
+              hovered
+                  .get()
+                  .map(|(x, y, text)| {
+                      let width = text.len() as f64 * 0.6 * REM + REM;
+                      let height = 1.5 * REM;
+                      let x = if x + REM / 2. + width > vector_x.max(0.) {
+                          x - REM / 2. - width
+                      } else {
+                          x + REM / 2.
+                      };
+                      let y = if y - 2. * REM < vector_y.min(0.) {
+                          y + REM / 2.
+                      } else {
+                          y - 2. * REM
+                      };
+                      view! {
+                        <g
+                          class=class_name("tooltip")
+                          transform=format!("translate({},{})", x, y)
+                          pointer-events="none"
+                        >
+                          <rect
+                            width=width
+                            height=height
+                            rx="3"
+                            fill="#ffffffdd"
+                            stroke="currentColor"
+                          ></rect>
+                          <text x=REM / 2. y=height / 2. dominant-baseline="middle">
+                            {text}
+                          </text>
+                        </g>
+                      }
+                  })
+          }}
+
         </g>
 
+        {legend
+            .then(|| {
+                view! {
+                  <g transform=translate_legend>
+                    <Legend
+                      items=legend_items
+                      visible=visible.clone()
+                      fills=legend_fills
+                      horizontal=horizontal
+                    />
+                  </g>
+                }
+            })}
+
       </SvgChart>
     }
 }
+
+// Bar from `from` to `to` (in pixel), vertical or horizontal, drawn as a rect of the given
+// width with rounded corners instead of a thick line, growing from `from` during `animate_ms`
+fn rounded_bar(
+    vertical: bool,
+    from: (f64, f64),
+    to: (f64, f64),
+    width: f64,
+    radius: f64,
+    fill: String,
+    animate_ms: Option<f64>,
+) -> HtmlElement<svg::Rect> {
+    let (x, y, w, h) = if vertical {
+        (
+            to.0 - width / 2.,
+            from.1.min(to.1),
+            width,
+            (to.1 - from.1).abs(),
+        )
+    } else {
+        (
+            from.0.min(to.0),
+            to.1 - width / 2.,
+            (to.0 - from.0).abs(),
+            width,
+        )
+    };
+    // The corners are rounded at most to a half circle
+    let radius = radius.min(width / 2.);
+    let animation = animate_ms.map(|ms| {
+        let dur = format!("{}ms", ms);
+        let (position, from_position, to_position, size, to_size) = if vertical {
+            ("y", from.1, y, "height", h)
+        } else {
+            ("x", from.0, x, "width", w)
+        };
+        view! {
+          <animate
+            attributeName=position
+            from=from_position
+            to=to_position
+            dur=dur.clone()
+            fill="freeze"
+          ></animate>
+          <animate attributeName=size from="0" to=to_size dur=dur fill="freeze"></animate>
+        }
+    });
+    view! {
+      <rect
+        class=class_name("bar")
+        x=x
+        y=y
+        width=w
+        height=h
+        rx=radius
+        fill=fill
+      >
+        {animation}
+      </rect>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The chart of a group with a title, rendered to a string
+    fn render(chart: coord::CartesianGroup) -> String {
+        let chart = chart.set_view(820, 620, 3, 100, 100, 20);
+        leptos::ssr::render_to_string(move || view! { <BarChartGroup chart=chart title="Sales"/> })
+            .to_string()
+    }
+
+    #[test]
+    fn renders_a_group_without_data_or_with_an_error() {
+        let empty = render(coord::CartesianGroup::new());
+        assert!(empty.contains("Sales"));
+
+        let mismatched = render(
+            coord::CartesianGroup::new()
+                .add_data(Series::from(vec!["A", "B"]), Series::from(vec![1.])),
+        );
+        assert!(mismatched.contains("Sales"));
+        assert!(mismatched.contains("2 x values and 1 y values in group 0"));
+
+        let chart = render(
+            coord::CartesianGroup::new()
+                .add_data(Series::from(vec!["A", "B"]), Series::from(vec![1., 3.])),
+        );
+        assert!(chart.contains("Sales"));
+        assert!(!chart.contains("in group"));
+    }
+}
@@ -0,0 +1,395 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        check_group, class_name, clip_path, error_message, report_error, series_color,
+        theme_axis_color, ChartError, ChartTheme, DebugRegion, EmptyMessage, SvgChart, TickDir,
+        REM,
+    },
+};
+use leptos::{component, svg::Svg, view, Callback, IntoView, NodeRef};
+use theta_chart::{color::Color, coord, series::Series};
+
+/// Component StackedBarChart for leptos
+///
+/// Each series is stacked on top of the previous ones for the same label.
+/// Positive values stack up from zero, negative values stack down separately.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["StackedBarChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let chart = CartesianGroup::new()
+///         .set_view(840, 640, 3, 50, 50, 20)
+///         .add_data(
+///             Series::from(vec!["A", "B", "C"]),
+///             Series::from(vec![0.7, 1.5, 1.9]),
+///         )
+///         .add_data(
+///             Series::from(vec!["A", "B", "C"]),
+///             Series::from(vec![0.3, -0.5, 0.9]),
+///         );
+///
+///     let color = Color::from("#ff0000");
+///
+///     view!{
+///         // color and shift_degrees are options
+///         <StackedBarChart chart=chart color=color shift_degrees=120. />
+///     }
+/// }
+/// ```
+/// ## Set view for StackedBarChart
+/// ```ignore
+///     ...
+///     .set_view(820, 620, 3, 100, 100, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis
+/// - `margin` : Margin for actual chart
+///
+/// ## About position_axes
+///
+/// - Top Left: 0
+/// - Top Right: 1
+/// - Bottom Right: 2
+/// - Bottom Left: 3
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About node_ref
+///
+/// Optional reference to the rendered `<svg>`, e.g. to save the chart with
+/// [`export_svg`](crate::export_svg) and [`download_svg`](crate::download_svg),
+/// or to measure it with `get_bounding_client_rect()`.
+///
+/// ## About responsive
+///
+/// Set `responsive=true` to scale the chart to the width of its container,
+/// keeping the aspect ratio of `set_view`, or `fixed_size=true` to give the chart the
+/// pixel size of `set_view`; else the `<svg>` only has a `viewBox`, sized by its CSS.
+///
+/// ## About title
+///
+/// Optional `title` and `subtitle`, centered in a space reserved above the chart,
+/// with the font sizes `title_size` (default 20) and `subtitle_size` (default 14).
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font. The series take the
+/// colors of its palette instead of shifting the hue of `color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn StackedBarChart(
+    chart: coord::CartesianGroup,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(default = 70.)] shift_degrees: f32,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+    #[prop(default = false)] responsive: bool,
+    #[prop(default = false)] fixed_size: bool,
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] subtitle: Option<String>,
+    #[prop(default = 1.25 * REM)] title_size: f64,
+    #[prop(default = 0.875 * REM)] subtitle_size: f64,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Stacked bar chart of {} series with {} bars per series",
+        chart.get_data().len(),
+        chart.get_data().first().map_or(0, |(x, _)| x.get_count())
+    );
+    let axis_color = theme_axis_color(None, &theme);
+    if let Err(error) = check_group(&chart) {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            node_ref=node_ref
+            responsive=responsive
+            fixed_size=fixed_size
+            title=title
+            subtitle=subtitle
+            title_size=title_size
+            subtitle_size=subtitle_size
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For Chart
+    let rec_chart = cview.get_rec_chart();
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For x-axis
+    let rec_xa = cview.get_rec_x_axis();
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+
+    // For y-axis
+    let rec_ya = cview.get_rec_y_axis();
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+
+    // For chart
+    let data = chart.get_data();
+    let x_is_label = matches!(data[0].0, Series::Label(_));
+
+    // The label series gives the position of the stack,
+    // the value series the height of each segment
+    let (series_label, stacks) = if x_is_label {
+        (
+            chart.get_ax_group(),
+            data.iter()
+                .map(|(x, y)| (x.to_stick(), y.to_stick()))
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        (
+            chart.get_ay_group(),
+            data.iter()
+                .map(|(x, y)| (y.to_stick(), x.to_stick()))
+                .collect::<Vec<_>>(),
+        )
+    };
+    let len_group = series_label.get_count();
+
+    // Running totals per label: (positive, negative)
+    let mut totals = vec![(0., 0.); len_group];
+    let segments = stacks
+        .into_iter()
+        .map(|(labels, values)| {
+            labels
+                .into_iter()
+                .enumerate()
+                .map(|(index, stick)| {
+                    let index_label = series_label.scale_index(stick.label);
+                    let value = values[index].value;
+                    let total: &mut (f64, f64) = &mut totals[index_label];
+                    let start = if value >= 0. {
+                        total.0 += value;
+                        total.0 - value
+                    } else {
+                        total.1 += value;
+                        total.1 - value
+                    };
+                    (index_label, start, start + value)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // The axis of values covers the stacked totals
+    let series_value = Series::from(
+        totals
+            .iter()
+            .flat_map(|total| vec![total.0, total.1])
+            .collect::<Vec<_>>(),
+    );
+    let (axes_x, axes_y) = if x_is_label {
+        (series_label.gen_axes(), series_value.gen_axes())
+    } else {
+        (series_value.gen_axes(), series_label.gen_axes())
+    };
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        node_ref=node_ref
+        responsive=responsive
+        fixed_size=fixed_size
+        title=title
+        subtitle=subtitle
+        title_size=title_size
+        subtitle_size=subtitle_size
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme.clone()
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
+          // For draw region of chart
+
+          <DebugRegion vector=rec_chart.get_vector()/>
+
+          {
+              let vector = rec_chart.get_vector();
+              let (length_label, length_value) = if x_is_label {
+                  (vector.get_x(), vector.get_y())
+              } else {
+                  (vector.get_y(), vector.get_x())
+              };
+              let width_col = series_label.scale(0.9) * length_label;
+              segments
+                  .into_iter()
+                  .enumerate()
+                  .map(|(index, segment)| {
+                      let color = series_color(&theme, &color, shift_degrees, index);
+                      let style = format!(
+                          "stroke:{};stroke-width:{}",
+                          color.to_string_hex(),
+                          width_col.abs() as u64,
+                      );
+                      segment
+                          .into_iter()
+                          .map(|(index_label, start, end)| {
+                              let position = series_label.scale(index_label as f64 + 0.5)
+                                  * length_label;
+                              let begin = series_value.scale(start) * length_value;
+                              let end = series_value.scale(end) * length_value;
+                              if x_is_label {
+                                  view! {
+                                    <line
+                                      class=class_name("bar")
+                                      x1=position
+                                      y1=begin
+                                      x2=position
+                                      y2=end
+                                      style=style.clone()
+                                    ></line>
+                                  }
+                              } else {
+                                  view! {
+                                    <line
+                                      class=class_name("bar")
+                                      x1=begin
+                                      y1=position
+                                      x2=end
+                                      y2=position
+                                      style=style.clone()
+                                    ></line>
+                                  }
+                              }
+                          })
+                          .collect::<Vec<_>>()
+                  })
+                  .collect::<Vec<_>>()
+          }
+
+        </g>
+      </SvgChart>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The chart of a group with a title, rendered to a string
+    fn render(chart: coord::CartesianGroup) -> String {
+        let chart = chart.set_view(820, 620, 3, 100, 100, 20);
+        leptos::ssr::render_to_string(
+            move || view! { <StackedBarChart chart=chart title="Sales"/> },
+        )
+        .to_string()
+    }
+
+    #[test]
+    fn renders_a_group_without_data_or_with_an_error() {
+        let empty = render(coord::CartesianGroup::new());
+        assert!(empty.contains("Sales"));
+
+        let mismatched = render(
+            coord::CartesianGroup::new()
+                .add_data(Series::from(vec!["A", "B"]), Series::from(vec![1.])),
+        );
+        assert!(mismatched.contains("Sales"));
+        assert!(mismatched.contains("2 x values and 1 y values in group 0"));
+
+        let chart = render(
+            coord::CartesianGroup::new()
+                .add_data(Series::from(vec!["A", "B"]), Series::from(vec![1., 3.])),
+        );
+        assert!(chart.contains("Sales"));
+        assert!(!chart.contains("in group"));
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        check_lengths, check_series, class_name, clip_path, error_message, report_error,
+        series_color, theme_axis_color, ChartError, ChartTheme, DebugRegion, EmptyMessage,
+        SvgChart, TickDir,
+    },
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{color::Color, coord, series::Series};
+
+/// Component BubbleChart for leptos
+///
+/// A scatter chart whose bubbles encode a third variable, the `sizes`, by their area.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["BubbleChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let chart = Cartesian::new(
+///         Series::from(vec![50, 70, 90, 110, 130]).set_range(40., 140.),
+///         Series::from(vec![7, 9, 8, 12, 14]).set_range(6., 16.),
+///     )
+///     .set_view(820, 620, 3, 100, 100, 20);
+///     let sizes = Series::from(vec![1., 4., 9., 2., 6.]);
+///
+///     view!{
+///         // color, shift_degrees, r_min and r_max are options
+///         <BubbleChart chart=chart sizes=sizes r_max=40. />
+///     }
+/// }
+/// ```
+/// ## Set view for BubbleChart
+/// ```ignore
+///     ...
+///     .set_view(820, 620, 3, 100, 100, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis
+/// - `margin` : Margin for actual chart
+///
+/// ## About position_axes
+///
+/// - Top Left: 0
+/// - Top Right: 1
+/// - Bottom Right: 2
+/// - Bottom Left: 3
+///
+/// ## About sizes
+///
+/// One size per point: the area of a bubble is proportional to its size, from a radius
+/// of `r_min` (default 4) to `r_max` (default 30) for the largest size.
+/// The zero or negative sizes, and the missing ones, have the radius `r_min`.
+/// The bubbles are translucent (`opacity`, default 0.6) and the largest are drawn first,
+/// so that the overlapping ones stay readable.
+///
+/// ## About color
+///
+/// All the bubbles have the `color`, unless `shift_degrees` is set:
+/// the hue of the i-th bubble is then shifted by i times `shift_degrees`.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font. With `shift_degrees`,
+/// the bubbles take the colors of its palette instead of shifting the hue of `color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn BubbleChart(
+    chart: coord::Cartesian,
+    sizes: Series,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(optional)] shift_degrees: Option<f32>,
+    #[prop(default = 4.0)] r_min: f64,
+    #[prop(default = 30.0)] r_max: f64,
+    #[prop(default = 0.6)] opacity: f64,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!("Bubble chart of {} bubbles", chart.get_ax().get_count());
+    let axis_color = theme_axis_color(None, &theme);
+    if let Err(error) = check_series(&chart.get_ax(), &chart.get_ay())
+        .and_then(|_| check_lengths(&chart.get_ax(), &chart.get_ay()))
+    {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For Chart
+    let rec_chart = cview.get_rec_chart();
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For x-axis
+    let rec_xa = cview.get_rec_x_axis();
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+    let series_x = chart.get_ax();
+    let axes_x = series_x.gen_axes();
+
+    // For y-axis
+    let rec_ya = cview.get_rec_y_axis();
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+    let series_y = chart.get_ay();
+    let axes_y = series_y.gen_axes();
+
+    // For sizes: the area is proportional to the size, the largest size has radius r_max
+    let sizes = sizes
+        .to_stick()
+        .into_iter()
+        .map(|stick| stick.value)
+        .collect::<Vec<_>>();
+    let max_size = sizes.iter().copied().fold(0., f64::max);
+    let radius = |index: usize| match sizes.get(index) {
+        Some(&size) if size > 0. && max_size > 0. => {
+            r_min + (r_max - r_min).max(0.) * (size / max_size).sqrt()
+        }
+        _ => r_min,
+    };
+
+    // For chart: the bubbles from the largest to the smallest
+    let vector = rec_chart.get_vector();
+    let ysticks = series_y.to_stick();
+    let mut bubbles = series_x
+        .to_stick()
+        .into_iter()
+        .enumerate()
+        .map(|(index, data)| {
+            let x = series_x.scale(data.value) * vector.get_x();
+            let y = series_y.scale(ysticks[index].value) * vector.get_y();
+            (index, x, y, radius(index))
+        })
+        .collect::<Vec<_>>();
+    bubbles.sort_by(|a, b| b.3.total_cmp(&a.3));
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme.clone()
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
+          // For draw region of chart
+
+          <DebugRegion vector=vector.clone()/>
+
+          <g class=class_name("bubbles")>
+            {bubbles
+                .into_iter()
+                .map(|(index, x, y, r)| {
+                    let color = match shift_degrees {
+                        Some(degrees) => series_color(&theme, &color, degrees, index),
+                        None => color.clone(),
+                    };
+                    view! {
+                      <circle
+                        class=class_name("bubble")
+                        cx=x
+                        cy=y
+                        r=r
+                        fill=color.to_string_hex()
+                        fill-opacity=opacity
+                        stroke=color.to_string_hex()
+                      ></circle>
+                    }
+                })
+                .collect::<Vec<_>>()}
+          </g>
+
+        </g>
+      </SvgChart>
+    }
+}
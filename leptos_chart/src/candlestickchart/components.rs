@@ -0,0 +1,355 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        check_lengths, check_series, class_name, clip_path, error_message, report_error,
+        theme_axis_color, time_series, ChartError, ChartTheme, DebugRegion, EmptyMessage, SvgChart,
+        TickDir,
+    },
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{color::Color, coord, series::Series};
+
+/// Component CandlestickChart for leptos
+///
+/// Draws the prices of each period as a candlestick: a thin wick from the low to the high
+/// and a body from the open to the close, e.g. for stock data (OHLC).
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["CandlestickChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     // The periods and the close prices
+///     let chart = Cartesian::new(
+///         Series::from(vec!["Mon", "Tue", "Wed", "Thu", "Fri"]),
+///         Series::from(vec![34., 36., 33., 33., 38.]),
+///     )
+///     .set_view(820, 620, 3, 50, 50, 20);
+///     let open = Series::from(vec![30., 34., 36., 33., 33.]);
+///     let high = Series::from(vec![35., 38., 37., 35., 39.]);
+///     let low = Series::from(vec![29., 33., 31., 31., 32.]);
+///
+///     view!{
+///         // increase_color, decrease_color and x_time are options
+///         <CandlestickChart chart=chart open=open high=high low=low />
+///     }
+/// }
+/// ```
+/// ## Set view for CandlestickChart
+/// ```ignore
+///     ...
+///     .set_view(820, 620, 3, 100, 100, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis
+/// - `margin` : Margin for actual chart
+///
+/// ## About position_axes
+///
+/// - Top Left: 0
+/// - Top Right: 1
+/// - Bottom Right: 2
+/// - Bottom Left: 3
+///
+/// ## About prices
+///
+/// The `chart` has the periods on x, labels or numbers, and the close prices on y.
+/// The `open`, `high` and `low` prices have one value per period too. The price axis
+/// spans all the prices. A period which closes at its open (a doji) has a flat body.
+///
+/// ## About colors
+///
+/// The candles closing at or above their open have the `increase_color` (default green),
+/// the others the `decrease_color` (default red).
+///
+/// ## About x_time
+///
+/// Set `x_time=true` when the x series holds Unix timestamps (in seconds, UTC):
+/// the sticks of the axis are at human-friendly dates, as for `LineChart`.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font. The candles keep their
+/// `increase_color` and `decrease_color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn CandlestickChart(
+    chart: coord::Cartesian,
+    open: Series,
+    high: Series,
+    low: Series,
+    #[prop(default = Color::from("#2ca02c"))] increase_color: Color,
+    #[prop(default = Color::from("#d62728"))] decrease_color: Color,
+    #[prop(default = false)] x_time: bool,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Candlestick chart of {} candles",
+        chart.get_ax().get_count()
+    );
+    let axis_color = theme_axis_color(None, &theme);
+    let (series_x, close) = (chart.get_ax(), chart.get_ay());
+    let checked = check_series(&series_x, &close)
+        .and_then(|_| check_lengths(&series_x, &close))
+        .and_then(|_| check_lengths(&series_x, &open))
+        .and_then(|_| check_lengths(&series_x, &high))
+        .and_then(|_| check_lengths(&series_x, &low))
+        .and_then(|_| match series_x {
+            Series::Label(_) | Series::Number(_) => Ok(()),
+            _ => Err(ChartError::UnsupportedSeries(
+                "The candlestick chart needs a series of labels or numbers on x".to_string(),
+            )),
+        });
+    if let Err(error) = checked {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For Chart
+    let rec_chart = cview.get_rec_chart();
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For x-axis: the labels at the middle of their periods, or the numbers padded by half
+    // the smallest period, for the first and last candles to be in the region in full
+    let rec_xa = cview.get_rec_x_axis();
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+    let periods = series_x
+        .to_stick()
+        .into_iter()
+        .map(|stick| stick.value)
+        .collect::<Vec<_>>();
+    let mut sorted = periods.clone();
+    sorted.sort_by(f64::total_cmp);
+    let step = sorted
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|step| *step > 0.)
+        .fold(f64::INFINITY, f64::min);
+    let step = if step.is_finite() { step } else { 1. };
+    let (scale_x, axes_x) = match series_x {
+        Series::Label(_) => {
+            let axes = series_x.gen_axes();
+            (series_x, axes)
+        }
+        _ => {
+            let (first, last) = (sorted[0], sorted[sorted.len() - 1]);
+            let padded = Series::from(
+                periods
+                    .iter()
+                    .copied()
+                    .chain([first - step / 2., last + step / 2.])
+                    .collect::<Vec<_>>(),
+            );
+            if x_time {
+                time_series(&padded).unwrap_or_else(|error| {
+                    report_error(&error, on_error);
+                    let axes = padded.gen_axes();
+                    (padded, axes)
+                })
+            } else {
+                let axes = padded.gen_axes();
+                (padded, axes)
+            }
+        }
+    };
+    let is_label = matches!(scale_x, Series::Label(_));
+
+    // For y-axis: all the prices
+    let rec_ya = cview.get_rec_y_axis();
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+    let values = |series: &Series| {
+        series
+            .to_stick()
+            .into_iter()
+            .map(|stick| stick.value)
+            .collect::<Vec<_>>()
+    };
+    let (open, high, low, close) = (values(&open), values(&high), values(&low), values(&close));
+    let series_y = Series::from([&open[..], &high, &low, &close].concat());
+    let axes_y = series_y.gen_axes();
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
+          // For draw region of chart
+
+          <DebugRegion vector=rec_chart.get_vector()/>
+
+          {
+              let vector = rec_chart.get_vector();
+              // The bodies take 60% of the smallest period, the labels have one each
+              let step = if is_label { 1. } else { step };
+              let width = (scale_x.scale(step) - scale_x.scale(0.)).abs() * vector.get_x().abs();
+              let width = width * 0.6;
+              periods
+                  .into_iter()
+                  .enumerate()
+                  .map(|(index, period)| {
+                      let x = if is_label {
+                          scale_x.scale(period + 0.5)
+                      } else {
+                          scale_x.scale(period)
+                      } * vector.get_x();
+                      let scale_y = |value: f64| series_y.scale(value) * vector.get_y();
+                      let (y_open, y_close) = (scale_y(open[index]), scale_y(close[index]));
+                      let color = if close[index] >= open[index] {
+                          increase_color.to_string_hex()
+                      } else {
+                          decrease_color.to_string_hex()
+                      };
+                      // A doji has a flat body, drawn as a line of the width of the bodies
+                      let body = if (y_open - y_close).abs() < 1. {
+                          view! {
+                            <line
+                              class=class_name("body")
+                              x1=x - width / 2.
+                              y1=y_close
+                              x2=x + width / 2.
+                              y2=y_close
+                              stroke=color.clone()
+                              stroke-width="1"
+                            ></line>
+                          }
+                              .into_view()
+                      } else {
+                          view! {
+                            <rect
+                              class=class_name("body")
+                              x=x - width / 2.
+                              y=y_open.min(y_close)
+                              width=width
+                              height=(y_open - y_close).abs()
+                              fill=color.clone()
+                              stroke=color.clone()
+                            ></rect>
+                          }
+                              .into_view()
+                      };
+                      view! {
+                        <g class=class_name("candle")>
+                          <line
+                            class=class_name("wick")
+                            x1=x
+                            y1=scale_y(low[index])
+                            x2=x
+                            y2=scale_y(high[index])
+                            stroke=color
+                            stroke-width="1"
+                          ></line>
+                          {body}
+                        </g>
+                      }
+                  })
+                  .collect::<Vec<_>>()
+          }
+
+        </g>
+      </SvgChart>
+    }
+}
@@ -0,0 +1,384 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        check_lengths, check_series, class_name, clip_path, error_message, gen_line_path,
+        report_error, same_series, series_color, theme_axis_color, ChartError, ChartTheme,
+        DebugRegion, EmptyMessage, LineKind, SvgChart, TickDir,
+    },
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{
+    color::Color,
+    coord,
+    coord::{Point, Rec, Vector},
+    series::Series,
+};
+
+/// Component ComboChart for leptos
+///
+/// Draws the bars of a chart with the line of a second chart on top, sharing their x series
+/// of labels, e.g. the revenue per month with its growth in percent. The line has its own
+/// y-axis at the opposite edge, unless `secondary_axis=false`.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["ComboChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let months = vec!["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+///     let chart = Cartesian::new(
+///         Series::from(months.clone()),
+///         Series::from(vec![120., 135., 128., 160., 172., 190.]),
+///     )
+///     .set_view(820, 620, 3, 50, 60, 20);
+///     let chart_line = Cartesian::new(
+///         Series::from(months),
+///         Series::from(vec![4., 12.5, -5.2, 25., 7.5, 10.5]),
+///     );
+///
+///     view!{
+///         // color, color_line, kind, y_title and y_title_right are options
+///         <ComboChart
+///             chart=chart
+///             chart_line=chart_line
+///             y_title="k$"
+///             y_title_right="%"
+///         />
+///     }
+/// }
+/// ```
+/// ## Set view for ComboChart
+/// ```ignore
+///     ...
+///     .set_view(820, 620, 3, 50, 60, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis, the same width is taken from the chart for the second one
+/// - `margin` : Margin for actual chart
+///
+/// Only the view of `chart` is used, `chart_line` needs no `set_view`.
+///
+/// ## About position_axes
+///
+/// - Top Left: 0
+/// - Top Right: 1
+/// - Bottom Right: 2
+/// - Bottom Left: 3
+///
+/// ## About secondary_axis
+///
+/// By default the line is on the scale of a second y-axis, at the opposite edge of the chart
+/// with the title `y_title_right`. Set `secondary_axis=false` to draw the bars and the line
+/// on the same y-axis, spanning the values of both charts, e.g. for a target or an average.
+///
+/// ## About color
+///
+/// The bars have the `color`, the line has `color_line` (default: the hue of `color`
+/// shifted by 180 degrees, or the second color of the palette of the `theme`).
+/// With `secondary_axis`, each y-axis has the color of its data, to tell them apart.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn ComboChart(
+    chart: coord::Cartesian,
+    chart_line: coord::Cartesian,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(optional)] color_line: Option<Color>,
+    #[prop(default = 2.0)] stroke_width: f64,
+    #[prop(optional)] kind: LineKind,
+    #[prop(default = true)] secondary_axis: bool,
+    #[prop(optional, into)] y_title: Option<String>,
+    #[prop(optional, into)] y_title_right: Option<String>,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Combo chart of {} bars and a line of {} points",
+        chart.get_ax().get_count(),
+        chart_line.get_ax().get_count()
+    );
+    let axis_color = theme_axis_color(None, &theme);
+    let checked = check_series(&chart.get_ax(), &chart.get_ay())
+        .and_then(|_| check_lengths(&chart.get_ax(), &chart.get_ay()))
+        .and_then(|_| check_series(&chart_line.get_ax(), &chart_line.get_ay()))
+        .and_then(|_| check_lengths(&chart_line.get_ax(), &chart_line.get_ay()))
+        .and_then(|_| match chart.get_ax() {
+            Series::Label(_) => Ok(()),
+            _ => Err(ChartError::UnsupportedSeries(
+                "The combo chart needs a series of labels on the x-axis".to_string(),
+            )),
+        })
+        .and_then(
+            |_| match same_series(&chart.get_ax(), &chart_line.get_ax()) {
+                true => Ok(()),
+                false => Err(ChartError::MismatchedSeries(1)),
+            },
+        );
+    if let Err(error) = checked {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+    let color_line = color_line.unwrap_or_else(|| series_color(&theme, &color, 180., 1));
+
+    // For Chart: with a secondary axis, narrowed by the width of the y-axis at its other edge
+    let rec_ya = cview.get_rec_y_axis();
+    let width_ya = match secondary_axis {
+        true => rec_ya.get_vector().get_x(),
+        false => 0.,
+    };
+    let rec_chart = cview.get_rec_chart();
+    let vector = Vector::new(
+        rec_chart.get_vector().get_x() + width_ya,
+        rec_chart.get_vector().get_y(),
+    );
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For x-axis
+    let rec_xa = cview.get_rec_x_axis();
+    let rec_xa = Rec::new(
+        rec_xa.get_origin(),
+        Vector::new(
+            rec_xa.get_vector().get_x() + width_ya,
+            rec_xa.get_vector().get_y(),
+        ),
+    );
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+    let series_x = chart.get_ax();
+    let axes_x = series_x.gen_axes();
+
+    // For y-axis of chart: without a secondary axis, shared with the line
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+    let series_y = match secondary_axis {
+        true => chart.get_ay(),
+        false => Series::from(
+            [chart.get_ay(), chart_line.get_ay()]
+                .iter()
+                .flat_map(|series| series.to_stick())
+                .map(|stick| stick.value)
+                .collect::<Vec<_>>(),
+        ),
+    };
+    let axes_y = series_y.gen_axes();
+
+    // For y-axis of chart_line: mirrored at the other edge of the chart
+    let rec_ya_right = Rec::new(
+        Point::new(
+            rec_chart.get_origin().get_x() + vector.get_x(),
+            rec_ya.get_origin().get_y(),
+        ),
+        Vector::new(-width_ya, rec_ya.get_vector().get_y()),
+    );
+    let translate_ya_right = format!(
+        "translate({},{})",
+        rec_ya_right.get_origin().get_x(),
+        rec_ya_right.get_origin().get_y()
+    );
+    let series_y_line = match secondary_axis {
+        true => chart_line.get_ay(),
+        false => series_y.clone(),
+    };
+    let axes_y_right = series_y_line.gen_axes();
+
+    // For chart: the bars and the points of the line at the centers of the labels
+    let center = |label: String| series_x.scale(series_x.scale_index(label) as f64 + 0.5);
+    let bars = chart
+        .get_ax()
+        .to_stick()
+        .into_iter()
+        .zip(chart.get_ay().to_stick())
+        .map(|(xstick, ystick)| {
+            (
+                center(xstick.label) * vector.get_x(),
+                series_y.scale(ystick.value) * vector.get_y(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let width_bar = series_x.scale(0.9) * vector.get_x();
+    let style_bar = format!(
+        "stroke:{};stroke-width:{}",
+        color.to_string_hex(),
+        width_bar.abs() as u64,
+    );
+    let points = chart_line
+        .get_ax()
+        .to_stick()
+        .into_iter()
+        .zip(chart_line.get_ay().to_stick())
+        .map(|(xstick, ystick)| {
+            (
+                center(xstick.label) * vector.get_x(),
+                series_y_line.scale(ystick.value) * vector.get_y(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let line = gen_line_path(&points, kind, 0);
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=if secondary_axis { Some(color.clone()) } else { axis_color }
+              title=y_title
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          {secondary_axis
+              .then(|| {
+                  view! {
+                    <g class=class_name("y-axis-right") transform=translate_ya_right>
+                      <YAxis
+                        region=rec_ya_right
+                        axes=axes_y_right
+                        color=Some(color_line.clone())
+                        title=y_title_right
+                        tick_size=tick_size
+                        tick_dir=tick_dir
+                      />
+                    </g>
+                  }
+              })}
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
+          // For draw region of chart
+
+          <DebugRegion vector=vector.clone()/>
+
+          <g class=class_name("bars")>
+            {bars
+                .into_iter()
+                .map(|(x, y)| {
+                    view! {
+                      <line
+                        class=class_name("bar")
+                        x1=x
+                        y1="0"
+                        x2=x
+                        y2=y
+                        style=style_bar.clone()
+                      ></line>
+                    }
+                })
+                .collect::<Vec<_>>()}
+          </g>
+
+          <g class=class_name("series")>
+            <path
+              class=class_name("line")
+              d=line
+              stroke=color_line.to_string_hex()
+              fill="none"
+              stroke-width=stroke_width
+            ></path>
+            {points
+                .into_iter()
+                .map(|(x, y)| {
+                    view! { <circle cx=x cy=y r="3" fill=color_line.to_string_hex()></circle> }
+                })
+                .collect::<Vec<_>>()}
+          </g>
+
+        </g>
+      </SvgChart>
+    }
+}
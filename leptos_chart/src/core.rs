@@ -1,9 +1,119 @@
 mod svg_chart;
 pub use self::svg_chart::*;
 
+#[cfg(any(
+    doc,
+    feature = "DonutChart",
+    feature = "GaugeChart",
+    feature = "PieChart",
+    feature = "RadarChart"
+))]
 mod svg_polar;
+#[cfg(any(
+    doc,
+    feature = "DonutChart",
+    feature = "GaugeChart",
+    feature = "PieChart",
+    feature = "RadarChart"
+))]
 pub use self::svg_polar::*;
 
+mod class;
+pub(crate) use self::class::*;
+
+mod debug;
+pub(crate) use self::debug::*;
+
+mod error;
+pub use self::error::*;
+
+mod export;
+pub use self::export::*;
+
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "HeatmapChart",
+    feature = "LineChart"
+))]
+mod gradient;
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "HeatmapChart",
+    feature = "LineChart"
+))]
+pub(crate) use self::gradient::*;
+
+mod legend;
+pub use self::legend::*;
+
+mod options;
+pub use self::options::*;
+
+mod path;
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DonutChart",
+    feature = "DualAxisLineChart",
+    feature = "GaugeChart",
+    feature = "LineChart",
+    feature = "PieChart",
+    feature = "Sparkline"
+))]
+pub(crate) use self::path::*;
+pub use self::path::{build_line_path, build_line_points, PointPixel};
+
+#[cfg(any(doc, feature = "BarChartGroup"))]
+mod pattern;
+#[cfg(any(doc, feature = "BarChartGroup"))]
+pub(crate) use self::pattern::*;
+
+mod polar;
+pub use self::polar::*;
+
+mod scale;
+#[cfg(any(
+    doc,
+    feature = "BarChart",
+    feature = "BarChartGroup",
+    feature = "HeatmapChart",
+    feature = "LineChart",
+    feature = "RadarChartGroup"
+))]
+pub(crate) use self::scale::*;
+pub use self::scale::{nice_range, Positions, SetBounds, Unscale};
+
+mod theme;
+#[cfg(any(
+    doc,
+    feature = "Axes",
+    feature = "DonutChart",
+    feature = "PieChart",
+    feature = "RadarChartGroup"
+))]
+pub(crate) use self::theme::*;
+pub use self::theme::{ChartTheme, Palette};
+
+#[cfg(any(doc, feature = "CandlestickChart", feature = "LineChart"))]
+mod time;
+#[cfg(any(doc, feature = "CandlestickChart", feature = "LineChart"))]
+pub(crate) use self::time::*;
+
+mod validate;
+#[cfg(any(
+    doc,
+    feature = "Axes",
+    feature = "DonutChart",
+    feature = "GaugeChart",
+    feature = "PieChart",
+    feature = "RadarChart",
+    feature = "RadarChartGroup"
+))]
+pub(crate) use self::validate::*;
+pub use self::validate::{CheckChart, FromCartesians, TryCartesian};
+
 // Font size for text in SVG
 #[cfg(any(doc, feature = "core"))]
 pub(crate) const REM: f64 = 16.;
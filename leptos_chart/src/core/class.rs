@@ -0,0 +1,17 @@
+use leptos::{provide_context, use_context};
+
+// Prefix of the classes, provided by the SVG wrapper of a chart to the elements inside
+#[derive(Clone)]
+struct ClassPrefix(Option<String>);
+
+pub(crate) fn provide_class_prefix(prefix: Option<String>) {
+    provide_context(ClassPrefix(prefix));
+}
+
+// Class of an element of a chart, e.g. `myapp-inner-chart` with the prefix `myapp`
+pub(crate) fn class_name(name: &str) -> String {
+    match use_context::<ClassPrefix>() {
+        Some(ClassPrefix(Some(prefix))) => format!("{}-{}", prefix, name),
+        _ => name.to_string(),
+    }
+}
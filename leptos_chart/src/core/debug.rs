@@ -0,0 +1,85 @@
+use leptos::*;
+use theta_chart::{color::Color, coord::Vector};
+
+// Color of the overlays of the regions, provided by the SVG wrapper of a chart to the
+// elements inside: set with the prop `debug` at runtime, or with the feature debug
+#[derive(Clone)]
+struct DebugColor(Option<String>);
+
+pub(crate) fn provide_debug(debug: bool, color: Option<Color>) {
+    let color = (debug || cfg!(feature = "debug"))
+        .then(|| color.unwrap_or(Color::from("#00ff00")).to_string_hex());
+    provide_context(DebugColor(color));
+}
+
+// Color of the overlays of the chart, none when they are not drawn
+pub(crate) fn debug_color() -> Option<String> {
+    use_context::<DebugColor>().and_then(|DebugColor(color)| color)
+}
+
+// Overlay of a region from its origin: the origin, the diagonal and the region itself,
+// in the color of the chart unless given, with a suffix to the ids of its elements
+#[component]
+pub(crate) fn DebugRegion(
+    vector: Vector,
+    #[prop(default = None)] color: Option<&'static str>,
+    #[prop(default = "")] id: &'static str,
+) -> impl IntoView {
+    debug_color().map(|debug| {
+        let color = color.map_or(debug, String::from);
+        let path = format!(
+            "M {},{} l {},{} l {},{} l {},{} Z",
+            0,
+            0,
+            vector.get_x(),
+            0,
+            0,
+            vector.get_y(),
+            -vector.get_x(),
+            0,
+        );
+        view! {
+          <circle id=format!("origin{}", id) cx="0" cy="0" r="3"></circle>
+          <line
+            x1="0"
+            y1="0"
+            x2=vector.get_x()
+            y2=vector.get_y()
+            stroke=color.clone()
+            stroke-opacity="0.2"
+            stroke-width="2"
+          ></line>
+          <path id=format!("region{}", id) d=path fill=color fill-opacity="0.2"></path>
+        }
+    })
+}
+
+// Overlay of a polar chart around its center: the center, the circle and a radius
+#[component]
+pub(crate) fn DebugCircle(radius: f64) -> impl IntoView {
+    debug_color().map(|color| {
+        view! {
+          <circle id="origin" cx=0 cy=0 r=3></circle>
+          <circle id="circle" cx=0 cy=0 r=radius fill=color.clone() fill-opacity="0.2"></circle>
+          <line
+            x1="0"
+            y1="0"
+            x2=0
+            y2=-radius
+            stroke=color
+            stroke-opacity="0.2"
+            stroke-width="2"
+          ></line>
+        }
+    })
+}
+
+// Overlay of the whole view of a chart, margin included
+#[component]
+pub(crate) fn DebugView(vector: Vector) -> impl IntoView {
+    debug_color().map(|color| {
+        view! {
+          <rect width=vector.get_x() height=vector.get_y() fill=color fill-opacity="0.07"></rect>
+        }
+    })
+}
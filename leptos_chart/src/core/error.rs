@@ -0,0 +1,128 @@
+use std::fmt;
+
+/// Error of the data or of the view of a chart
+///
+/// Its message is the one logged by the components for the same error, and the one shown
+/// in the chart instead of its data. The components also pass it to their `on_error`
+/// callback, e.g. to show another UI when the data can not be drawn.
+///
+/// # Examples
+/// ```ignore
+/// let on_error = Callback::new(|error: ChartError| match error {
+///     ChartError::EmptySeries(_) | ChartError::NoData => set_fallback("No data yet"),
+///     error => set_fallback(&error.to_string()),
+/// });
+///
+/// view! { <LineChart chart=chart on_error=on_error /> }
+/// ```
+#[cfg(any(doc, feature = "core"))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartError {
+    /// The series of the axis (`"x"` or `"y"`) has no values
+    EmptySeries(&'static str),
+    /// The series have different counts of values
+    MismatchedLengths {
+        /// Count of the x values
+        x: usize,
+        /// Count of the y values
+        y: usize,
+    },
+    /// The view can not hold the chart, for the given reason
+    InvalidView(String),
+    /// The group has no data
+    NoData,
+    /// The x series of the chart at the index is not of the same kind as the first one
+    MismatchedKinds(usize),
+    /// The x labels of the chart at the index differ from the ones of the first chart
+    MismatchedLabels(usize),
+    /// The x series of the chart at the index differs from the x series shared by the charts
+    MismatchedSeries(usize),
+    /// The series are not of the kind needed by the chart, with the message saying which
+    UnsupportedSeries(String),
+    /// A value or a parameter is out of its range, with the message saying which
+    OutOfRange(String),
+    /// The error of the data at the index of a group
+    InGroup {
+        /// Index of the data in the group
+        index: usize,
+        /// Error of the data
+        error: Box<ChartError>,
+    },
+}
+
+impl fmt::Display for ChartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartError::EmptySeries(axis) => write!(f, "The {} series is empty", axis),
+            ChartError::MismatchedLengths { x, y } => write!(
+                f,
+                "The lengths of the series are not equal: {} x values and {} y values",
+                x, y
+            ),
+            ChartError::InvalidView(reason) => write!(f, "The view is invalid: {}", reason),
+            ChartError::NoData => write!(f, "The chart has no data"),
+            ChartError::MismatchedKinds(index) => write!(
+                f,
+                "The x series of chart {} is not of the same kind as the first one",
+                index
+            ),
+            ChartError::MismatchedLabels(index) => write!(
+                f,
+                "The x labels of chart {} differ from the ones of the first chart",
+                index
+            ),
+            ChartError::MismatchedSeries(index) => write!(
+                f,
+                "The x series of chart {} differs from the shared x series",
+                index
+            ),
+            ChartError::UnsupportedSeries(message) | ChartError::OutOfRange(message) => {
+                write!(f, "{}", message)
+            }
+            ChartError::InGroup { index, error } => write!(f, "{} in group {}", error, index),
+        }
+    }
+}
+
+impl std::error::Error for ChartError {}
+
+// Log the error of a chart and pass it to the on_error callback of its component
+#[cfg(any(
+    doc,
+    feature = "Axes",
+    feature = "DonutChart",
+    feature = "GaugeChart",
+    feature = "PieChart",
+    feature = "RadarChart",
+    feature = "RadarChartGroup",
+    feature = "Sparkline"
+))]
+pub(crate) fn report_error(error: &ChartError, on_error: Option<leptos::Callback<ChartError>>) {
+    use leptos::Callable;
+
+    log::error!("{}", error);
+    if let Some(on_error) = on_error {
+        on_error.call(error.clone());
+    }
+}
+
+// Message shown in a chart which can not be drawn: the empty_message without data,
+// else the message of the error
+#[cfg(any(
+    doc,
+    feature = "Axes",
+    feature = "DonutChart",
+    feature = "GaugeChart",
+    feature = "PieChart",
+    feature = "RadarChart",
+    feature = "RadarChartGroup"
+))]
+pub(crate) fn error_message(error: &ChartError, empty_message: Option<String>) -> Option<String> {
+    match error {
+        ChartError::EmptySeries(_) | ChartError::NoData => empty_message,
+        ChartError::InGroup { error, .. } if matches!(**error, ChartError::EmptySeries(_)) => {
+            empty_message
+        }
+        error => Some(error.to_string()),
+    }
+}
@@ -0,0 +1,192 @@
+use leptos::{svg::Svg, NodeRef};
+use theta_chart::{
+    coord::{Cartesian, CartesianGroup},
+    series::Series,
+};
+use wasm_bindgen::{JsCast, JsValue};
+
+const XMLNS: &str = "http://www.w3.org/2000/svg";
+
+/// Serialize the rendered chart to a standalone SVG string
+///
+/// The `node_ref` is the one given to the `node_ref` prop of a chart.
+/// The xmlns attribute and the width/height (from the `viewBox`) are set,
+/// so the string can be saved as an `.svg` file.
+/// An empty string is returned when the chart is not mounted.
+///
+/// # Examples
+/// ```ignore
+/// let node_ref = create_node_ref::<svg::Svg>();
+/// let on_click = move |_| download_svg("chart.svg", &export_svg(node_ref));
+///
+/// view!{
+///     <LineChart chart=chart node_ref=node_ref />
+///     <button on:click=on_click>"Download"</button>
+/// }
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub fn export_svg(node_ref: NodeRef<Svg>) -> String {
+    // Work on a copy, so the rendered chart is left untouched
+    let Some(svg) = node_ref
+        .get_untracked()
+        .and_then(|svg| svg.clone_node_with_deep(true).ok())
+        .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+    else {
+        return String::default();
+    };
+
+    let _ = svg.set_attribute("xmlns", XMLNS);
+    let view_box = svg.get_attribute("viewBox").unwrap_or_default();
+    if let [_, _, width, height] = view_box.split_whitespace().collect::<Vec<_>>()[..] {
+        let _ = svg.set_attribute("width", width);
+        let _ = svg.set_attribute("height", height);
+    }
+
+    svg.outer_html()
+}
+
+/// Trigger a browser download of `contents` as an SVG file named `filename`
+///
+/// Usually called with the result of [`export_svg`].
+#[cfg(any(doc, feature = "core"))]
+pub fn download_svg(filename: &str, contents: &str) {
+    if let Err(error) = try_download_svg(filename, contents) {
+        log::error!("Download of {} failed: {:?}", filename, error);
+    }
+}
+
+/// Data of a chart as CSV, e.g. for a "Download data" button next to the chart
+///
+/// The first row is the header: `label` for a column of labels, `x` or `y` for numbers and
+/// `time` for times, the names `Series 1`, `Series 2`, ... for the values of a group.
+/// The fields with a comma, a quote or a line break are quoted.
+///
+/// # Examples
+/// ```ignore
+/// let chart = Cartesian::new(
+///     Series::from(vec!["A", "B"]),
+///     Series::from(vec![1.5, 4.]),
+/// );
+/// assert_eq!(chart.to_csv(), "label,y\nA,1.5\nB,4\n");
+///
+/// // One row per x of the group, empty where a series has no value
+/// let group = CartesianGroup::new()
+///     .add_data(Series::from(vec!["A", "B"]), Series::from(vec![1., 2.]))
+///     .add_data(Series::from(vec!["B"]), Series::from(vec![3.]));
+/// assert_eq!(group.to_csv(), "label,Series 1,Series 2\nA,1,\nB,2,3\n");
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait ToCsv {
+    /// The CSV of the data, one line per row ended by a line feed
+    fn to_csv(&self) -> String;
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl ToCsv for Cartesian {
+    fn to_csv(&self) -> String {
+        let (series_x, series_y) = (self.get_ax(), self.get_ay());
+        let header = [csv_header(&series_x, "x"), csv_header(&series_y, "y")];
+        let rows = series_x
+            .to_stick()
+            .into_iter()
+            .zip(series_y.to_stick())
+            .map(|(x, y)| vec![x.label, y.label]);
+        csv_lines(header.map(String::from).to_vec(), rows)
+    }
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl ToCsv for CartesianGroup {
+    fn to_csv(&self) -> String {
+        let data = self.get_data();
+        let Some((first, _)) = data.first() else {
+            return String::default();
+        };
+
+        // The x of all the series, in their order for labels, else sorted by value
+        let mut keys = Vec::<(String, f64)>::new();
+        for stick in data.iter().flat_map(|(x, _)| x.to_stick()) {
+            if !keys.iter().any(|(label, _)| *label == stick.label) {
+                keys.push((stick.label, stick.value));
+            }
+        }
+        if !matches!(first, Series::Label(_)) {
+            keys.sort_by(|a, b| a.1.total_cmp(&b.1));
+        }
+
+        let header = std::iter::once(csv_header(first, "x").to_string())
+            .chain((1..=data.len()).map(|index| format!("Series {}", index)))
+            .collect();
+        let values = data
+            .iter()
+            .map(|(x, y)| {
+                x.to_stick()
+                    .into_iter()
+                    .map(|stick| stick.label)
+                    .zip(y.to_stick().into_iter().map(|stick| stick.label))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let rows = keys.into_iter().map(|(key, _)| {
+            let cells = values.iter().map(|pairs| {
+                pairs
+                    .iter()
+                    .find(|(label, _)| *label == key)
+                    .map_or(String::default(), |(_, value)| value.clone())
+            });
+            std::iter::once(key.clone()).chain(cells).collect()
+        });
+        csv_lines(header, rows)
+    }
+}
+
+// Header of the column of a series: by its kind, `number` for the numbers
+fn csv_header(series: &Series, number: &'static str) -> &'static str {
+    match series {
+        Series::Label(_) => "label",
+        Series::Number(_) => number,
+        Series::Time(_) => "time",
+    }
+}
+
+// Lines of the header and the rows, with their fields quoted when needed
+fn csv_lines(header: Vec<String>, rows: impl Iterator<Item = Vec<String>>) -> String {
+    std::iter::once(header)
+        .chain(rows)
+        .map(|fields| {
+            let line = fields
+                .iter()
+                .map(|field| {
+                    if field.contains([',', '"', '\n', '\r']) {
+                        format!("\"{}\"", field.replace('"', "\"\""))
+                    } else {
+                        field.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            line + "\n"
+        })
+        .collect()
+}
+
+fn try_download_svg(filename: &str, contents: &str) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("No document"))?;
+
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("image/svg+xml");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<web_sys::HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    web_sys::Url::revoke_object_url(&url)
+}
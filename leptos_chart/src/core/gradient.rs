@@ -0,0 +1,63 @@
+use theta_chart::color::Color;
+
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+use {
+    leptos::{view, IntoView},
+    std::sync::atomic::{AtomicUsize, Ordering},
+};
+
+// Count of the gradients of the page, for their ids to be unique over several charts
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+static GRADIENTS: AtomicUsize = AtomicUsize::new(0);
+
+// Unique prefix of the ids of the gradients of a chart
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+pub(crate) fn gradient_id() -> String {
+    format!(
+        "leptos-chart-gradient-{}",
+        GRADIENTS.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+// Color mixed with white, by `amount` from 0 (the color) to 1 (white)
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+pub(crate) fn lighten(color: &Color, amount: f64) -> Color {
+    mix(color, &Color::from("#ffffff"), amount)
+}
+
+// Color interpolated between two colors, by `amount` from 0 (`from`) to 1 (`to`)
+pub(crate) fn mix(from: &Color, to: &Color, amount: f64) -> Color {
+    let (from, to) = (from.to_string_hex(), to.to_string_hex());
+    let component = |hex: &str, index: usize| {
+        u8::from_str_radix(&hex[index..index + 2], 16).unwrap_or_default() as f64
+    };
+    let mix = |index: usize| {
+        let (from, to) = (component(&from, index), component(&to, index));
+        (from + (to - from) * amount.clamp(0., 1.)).round() as u8
+    };
+    Color::from(format!("#{:02X}{:02X}{:02X}", mix(1), mix(3), mix(5)).as_str())
+}
+
+// Linear gradient from a lighter shade of the color at `from` to the color at `to`,
+// in the coordinates of the elements using it (not their bounding box, empty for a line)
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+pub(crate) fn linear_gradient(
+    id: String,
+    color: &Color,
+    from: (f64, f64),
+    to: (f64, f64),
+) -> impl IntoView {
+    view! {
+      <linearGradient
+        id=id
+        gradientUnits="userSpaceOnUse"
+        x1=from.0
+        y1=from.1
+        x2=to.0
+        y2=to.1
+      >
+        <stop offset="0" stop-color=lighten(color, 0.6).to_string_hex()></stop>
+        <stop offset="1" stop-color=color.to_string_hex()></stop>
+      </linearGradient>
+    }
+}
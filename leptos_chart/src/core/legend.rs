@@ -0,0 +1,260 @@
+use crate::core::{class_name, REM};
+use leptos::*;
+use theta_chart::color::Color;
+
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "LineChartGroup",
+    feature = "RadarChartGroup"
+))]
+use {
+    crate::core::LegendPos,
+    theta_chart::coord::{Point, Rec, Vector},
+};
+
+// Width of an item of the legend: the swatch, the gap and the text estimated from its length
+fn item_width(label: &str) -> f64 {
+    1.75 * REM + label.chars().count() as f64 * 0.6 * REM
+}
+
+// Bounds of a region in the coordinates of the view: left, top, right, bottom
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "LineChartGroup",
+    feature = "RadarChartGroup"
+))]
+fn bounds(region: &Rec) -> (f64, f64, f64, f64) {
+    let (origin, vector) = (region.get_origin(), region.get_vector());
+    let (x, y) = (origin.get_x(), origin.get_y());
+    let (dx, dy) = (vector.get_x(), vector.get_y());
+    (x.min(x + dx), y.min(y + dy), x.max(x + dx), y.max(y + dy))
+}
+
+// Region without `size` pixels at the side `pos`, its origin kept at the same corner
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "LineChartGroup",
+    feature = "RadarChartGroup"
+))]
+fn shrink(region: &Rec, pos: LegendPos, size: f64) -> Rec {
+    let (origin, vector) = (region.get_origin(), region.get_vector());
+    let (mut x, mut y) = (origin.get_x(), origin.get_y());
+    let (mut dx, mut dy) = (vector.get_x(), vector.get_y());
+    match pos {
+        LegendPos::Left if dx >= 0. => (x, dx) = (x + size, dx - size),
+        LegendPos::Right if dx < 0. => (x, dx) = (x - size, dx + size),
+        LegendPos::Left | LegendPos::Right => dx -= size * dx.signum(),
+        LegendPos::Top if dy >= 0. => (y, dy) = (y + size, dy - size),
+        LegendPos::Bottom if dy < 0. => (y, dy) = (y - size, dy + size),
+        LegendPos::Top | LegendPos::Bottom => dy -= size * dy.signum(),
+    }
+    Rec::new(Point::new(x, y), Vector::new(dx, dy))
+}
+
+// Region moved by `size` pixels from the side `pos` to the inside of the view
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "LineChartGroup",
+    feature = "RadarChartGroup"
+))]
+fn shift(region: &Rec, pos: LegendPos, size: f64) -> Rec {
+    let origin = region.get_origin();
+    let (dx, dy) = match pos {
+        LegendPos::Top => (0., size),
+        LegendPos::Bottom => (0., -size),
+        LegendPos::Left => (size, 0.),
+        LegendPos::Right => (-size, 0.),
+    };
+    Rec::new(
+        Point::new(origin.get_x() + dx, origin.get_y() + dy),
+        region.get_vector(),
+    )
+}
+
+// Regions of the chart, the x-axis and the y-axis with the room of the legend taken at the
+// side `pos`, and the translation of the legend in this room; unchanged without legend.
+// The region of the chart and the axis along the side shrink, the other axis moves with the
+// edge of the chart when at this side.
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "LineChartGroup",
+    feature = "RadarChartGroup"
+))]
+pub(crate) fn legend_layout(
+    pos: Option<LegendPos>,
+    items: &[(String, Color)],
+    regions: (Rec, Rec, Rec),
+) -> ((Rec, Rec, Rec), String) {
+    let Some(pos) = pos else {
+        return (regions, String::new());
+    };
+    let (rec_chart, rec_xa, rec_ya) = regions;
+    let (width, height) = legend_size(items, pos);
+    let chart = bounds(&rec_chart);
+    let (xa, ya) = (bounds(&rec_xa), bounds(&rec_ya));
+    let all = (
+        chart.0.min(xa.0).min(ya.0),
+        chart.1.min(xa.1).min(ya.1),
+        chart.2.max(xa.2).max(ya.2),
+        chart.3.max(xa.3).max(ya.3),
+    );
+    let center =
+        |bounds: (f64, f64, f64, f64)| ((bounds.0 + bounds.2) / 2., (bounds.1 + bounds.3) / 2.);
+    let ((chart_x, chart_y), (xa_y, ya_x)) = (center(chart), (center(xa).1, center(ya).0));
+
+    // The column is anchored at its top-right corner, the row at its top-left corner
+    let (size, translate) = match pos {
+        LegendPos::Top => (height + REM, (chart.0, all.1)),
+        LegendPos::Bottom => (height + REM, (chart.0, all.3 - height)),
+        LegendPos::Left => (width + REM, (all.0 + width, chart.1)),
+        LegendPos::Right => (width + REM, (all.2, chart.1)),
+    };
+    let regions = match pos {
+        LegendPos::Top | LegendPos::Bottom => {
+            let at_side = (pos == LegendPos::Top) == (xa_y < chart_y);
+            let rec_xa = if at_side {
+                shift(&rec_xa, pos, size)
+            } else {
+                rec_xa
+            };
+            (
+                shrink(&rec_chart, pos, size),
+                rec_xa,
+                shrink(&rec_ya, pos, size),
+            )
+        }
+        LegendPos::Left | LegendPos::Right => {
+            let at_side = (pos == LegendPos::Left) == (ya_x < chart_x);
+            let rec_ya = if at_side {
+                shift(&rec_ya, pos, size)
+            } else {
+                rec_ya
+            };
+            (
+                shrink(&rec_chart, pos, size),
+                shrink(&rec_xa, pos, size),
+                rec_ya,
+            )
+        }
+    };
+    (
+        regions,
+        format!("translate({},{})", translate.0, translate.1),
+    )
+}
+
+// Size of the legend of the items, in a row above or below the chart, else in a column
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "LineChartGroup",
+    feature = "RadarChartGroup"
+))]
+pub(crate) fn legend_size(items: &[(String, Color)], pos: LegendPos) -> (f64, f64) {
+    let widths = items.iter().map(|(label, _)| item_width(label));
+    match pos {
+        LegendPos::Top | LegendPos::Bottom => (widths.sum(), 0.75 * REM),
+        LegendPos::Left | LegendPos::Right => (
+            widths.fold(0., f64::max),
+            (items.len() as f64 * 1.5 - 0.75).max(0.) * REM,
+        ),
+    }
+}
+
+/// Component Legend for leptos
+///
+/// Draws a colored swatch with a label for each item, one item per row.
+/// The legend is anchored at its top-right corner, so it must be placed
+/// inside an `<svg>`, e.g. in a `<g transform="translate(x,y)">`.
+/// With `horizontal=true`, the items are in a single row anchored at its top-left corner.
+///
+/// With the optional `visible` flags (one per item), a click on an item toggles its flag
+/// and a hidden item is dimmed. The chart is expected to hide the series of a false flag.
+///
+/// With the optional `fills` (one per item), the swatches are painted with them instead of
+/// the colors, e.g. `url(#id)` of a pattern defined in the same page.
+///
+/// # Examples
+/// ```ignore
+/// let items = vec![
+///     ("Sales".to_string(), Color::from("#ff0000")),
+///     ("Costs".to_string(), Color::from("#0000ff")),
+/// ];
+///
+/// view!{
+///     <svg viewBox="0 0 200 100">
+///         <g transform="translate(190,10)">
+///             <Legend items=items />
+///         </g>
+///     </svg>
+/// }
+/// ```
+#[cfg(any(doc, feature = "core"))]
+#[component]
+pub fn Legend(
+    items: Vec<(String, Color)>,
+    #[prop(optional)] visible: Vec<RwSignal<bool>>,
+    #[prop(optional)] fills: Vec<String>,
+    #[prop(optional)] horizontal: bool,
+) -> impl IntoView {
+    // In a row: the swatch then the text from the left of each item, at its offset
+    let (swatch_x, text_x, anchor) = if horizontal {
+        (0., REM, "start")
+    } else {
+        (-0.75 * REM, -REM, "end")
+    };
+    let offsets = items
+        .iter()
+        .scan(0., |offset, (label, _)| {
+            let start = *offset;
+            *offset += item_width(label);
+            Some(start)
+        })
+        .collect::<Vec<_>>();
+    view! {
+      <g class=class_name("legend")>
+        {items
+            .into_iter()
+            .enumerate()
+            .map(|(index, (label, color))| {
+                let translate = if horizontal {
+                    format!("translate({},0)", offsets[index])
+                } else {
+                    format!("translate(0,{})", index as f64 * 1.5 * REM)
+                };
+                let shown = visible.get(index).copied();
+                let fill = fills
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| color.to_string_hex());
+                view! {
+                  <g
+                    class=class_name("legend-item")
+                    transform=translate
+                    cursor=shown.map(|_| "pointer")
+                    opacity=move || match shown {
+                        Some(shown) if !shown.get() => Some("0.4"),
+                        _ => None,
+                    }
+                    on:click=move |_| {
+                        if let Some(shown) = shown {
+                            shown.update(|shown| *shown = !*shown);
+                        }
+                    }
+                  >
+                    <rect x=swatch_x width=0.75 * REM height=0.75 * REM fill=fill></rect>
+                    <text x=text_x y=0.375 * REM text-anchor=anchor dominant-baseline="middle">
+                      {label}
+                    </text>
+                  </g>
+                }
+            })
+            .collect::<Vec<_>>()}
+      </g>
+    }
+}
@@ -0,0 +1,311 @@
+use theta_chart::color::Color;
+
+/// Orientation of the data mapping of a chart
+///
+/// - `Horizontal`: the x series drives the horizontal position
+/// - `Vertical`: the x series drives the vertical position, the y series the horizontal one
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Orientation {
+    /// Series x on horizontal axis, series y on vertical axis
+    #[default]
+    Horizontal,
+    /// Series y on horizontal axis, series x on vertical axis
+    Vertical,
+}
+
+/// Interpolation of the line between the points of a chart
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LineKind {
+    /// Straight segments between the points
+    #[default]
+    Linear,
+    /// Cubic curve which preserves monotonicity, no overshoot for monotonic data
+    Monotone,
+    /// Cubic curve passing through the points (uniform Catmull-Rom)
+    CatmullRom,
+    /// Steps jumping to the next value at the start of each interval
+    StepBefore,
+    /// Steps holding the value until the end of each interval
+    StepAfter,
+}
+
+/// Scale of the values on an axis, or along the colors of a heatmap
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Scale {
+    /// Values are mapped linearly
+    #[default]
+    Linear,
+    /// Values are mapped in log10 space, sticks at the powers of ten
+    Log10,
+}
+
+/// Stroke style of a line
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LineStyle {
+    /// Continuous line
+    #[default]
+    Solid,
+    /// Dashes, e.g. for projected data
+    Dashed,
+    /// Dots
+    Dotted,
+}
+
+impl LineStyle {
+    // Value of the stroke-dasharray attribute, none for a solid line
+    #[cfg(any(doc, feature = "LineChart", feature = "LineChartGroup"))]
+    pub(crate) fn dasharray(&self) -> Option<&'static str> {
+        match self {
+            LineStyle::Solid => None,
+            LineStyle::Dashed => Some("6,4"),
+            LineStyle::Dotted => Some("1,3"),
+        }
+    }
+}
+
+/// Style of a line among several ones, e.g. a bold line for the actual values
+/// and a thin dashed one for the forecast
+///
+/// # Examples
+/// ```ignore
+/// let series_styles = vec![
+///     LineSeriesStyle { stroke_width: 3., ..Default::default() },
+///     LineSeriesStyle {
+///         stroke_width: 1.,
+///         style: LineStyle::Dashed,
+///         color: Some(Color::from("#888888")),
+///     },
+/// ];
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineSeriesStyle {
+    /// Width of the line (default 2)
+    pub stroke_width: f64,
+    /// Dash pattern of the line (default `LineStyle::Solid`)
+    pub style: LineStyle,
+    /// Color of the line and its points, instead of the color of the series
+    pub color: Option<Color>,
+}
+
+impl Default for LineSeriesStyle {
+    fn default() -> Self {
+        LineSeriesStyle {
+            stroke_width: 2.,
+            style: LineStyle::Solid,
+            color: None,
+        }
+    }
+}
+
+/// Callout at a point of the data of a chart, e.g. "peak" at the highest value
+///
+/// # Examples
+/// ```ignore
+/// let annotations = vec![
+///     Annotation::new(3., 5., "peak"),
+///     Annotation {
+///         offset: Some((40., -30.)),
+///         color: Some(Color::from("#d62728")),
+///         ..Annotation::new(6., 1., "outage")
+///     },
+/// ];
+/// ```
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    /// Value of x of the point
+    pub x: f64,
+    /// Value of y of the point
+    pub y: f64,
+    /// Text of the callout
+    pub text: String,
+    /// Offset in pixels of the text from the point, joined by a leader line,
+    /// else the text is just above the point
+    pub offset: Option<(f64, f64)>,
+    /// Color of the marker and the leader line, else the color of the texts
+    pub color: Option<Color>,
+}
+
+impl Annotation {
+    /// Annotation with the `text` just above the point (`x`, `y`)
+    pub fn new(x: f64, y: f64, text: impl Into<String>) -> Self {
+        Annotation {
+            x,
+            y,
+            text: text.into(),
+            offset: None,
+            color: None,
+        }
+    }
+}
+
+/// Shape of the corners of a line, its `stroke-linejoin`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LineJoin {
+    /// Sharp corners, beveled when too spiky (SVG default)
+    #[default]
+    Miter,
+    /// Rounded corners
+    Round,
+    /// Cut corners
+    Bevel,
+}
+
+#[cfg(any(doc, feature = "LineChart"))]
+impl LineJoin {
+    // Value of the stroke-linejoin attribute
+    pub(crate) fn attribute(&self) -> &'static str {
+        match self {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        }
+    }
+}
+
+/// Shape of the ends of a line, its `stroke-linecap`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LineCap {
+    /// Ends cut at the points (SVG default)
+    #[default]
+    Butt,
+    /// Ends rounded beyond the points
+    Round,
+    /// Ends squared beyond the points
+    Square,
+}
+
+#[cfg(any(doc, feature = "LineChart"))]
+impl LineCap {
+    // Value of the stroke-linecap attribute
+    pub(crate) fn attribute(&self) -> &'static str {
+        match self {
+            LineCap::Butt => "butt",
+            LineCap::Round => "round",
+            LineCap::Square => "square",
+        }
+    }
+}
+
+/// Shape of the point markers of a chart
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Marker {
+    /// Circle of radius `point_radius`
+    #[default]
+    Circle,
+    /// Square of side twice `point_radius`
+    Square,
+    /// Triangle pointing up, centered at its centroid
+    Triangle,
+    /// Square rotated by 45 degrees
+    Diamond,
+}
+
+/// Order of the labels of a chart, e.g. of the bars for a ranking
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Sort {
+    /// In the order of the data
+    #[default]
+    None,
+    /// From the smallest to the largest value
+    Ascending,
+    /// From the largest to the smallest value
+    Descending,
+}
+
+/// Drawing of the missing values (NaN) of a line
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum GapMode {
+    /// Break the line at the missing values, in separate segments
+    #[default]
+    Skip,
+    /// Connect the points around the missing values
+    Connect,
+    /// Draw the missing values at zero
+    Zero,
+}
+
+/// Side of the y-axis of a chart
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum YAxisSide {
+    /// As laid out by the `position_origin` of the view (default)
+    #[default]
+    Left,
+    /// At the right edge of the chart, the data still fill the region
+    Right,
+}
+
+/// Direction of the layout of a chart and of its texts
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Dir {
+    /// From left to right (default)
+    #[default]
+    Ltr,
+    /// From right to left, e.g. for Arabic or Hebrew: the chart is mirrored
+    Rtl,
+}
+
+impl Dir {
+    // Value of the direction attribute
+    pub(crate) fn attribute(&self) -> &'static str {
+        match self {
+            Dir::Ltr => "ltr",
+            Dir::Rtl => "rtl",
+        }
+    }
+
+    // Text anchor at the same side of the text whatever the direction, from its anchor
+    // from left to right: start is the left of the text, end its right
+    #[cfg(any(doc, feature = "Axes"))]
+    pub(crate) fn anchor(&self, anchor: &'static str) -> &'static str {
+        match (self, anchor) {
+            (Dir::Rtl, "start") => "end",
+            (Dir::Rtl, "end") => "start",
+            _ => anchor,
+        }
+    }
+}
+
+/// Side of a chart where its legend is drawn, in a space taken from the region of the chart
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LegendPos {
+    /// Above the chart, the items in a row
+    Top,
+    /// Below the chart and its x-axis, the items in a row
+    Bottom,
+    /// Left of the chart, the items in a column
+    Left,
+    /// Right of the chart, the items in a column (default)
+    #[default]
+    Right,
+}
+
+/// Direction of the tick marks of an axis
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TickDir {
+    /// Out of the chart, toward the labels (default)
+    #[default]
+    Outside,
+    /// Into the chart, e.g. for a lighter style
+    Inside,
+}
+
+#[cfg(any(doc, feature = "Axes"))]
+impl TickDir {
+    // End of a tick mark from the axis, along the direction from the chart to the labels
+    // (`sign` 1 or -1)
+    pub(crate) fn tick_end(&self, tick_size: f64, sign: f64) -> f64 {
+        match self {
+            TickDir::Outside => sign * tick_size,
+            TickDir::Inside => -sign * tick_size,
+        }
+    }
+
+    // Offset of the labels from the axis, at least REM and past the outside tick marks
+    pub(crate) fn label_offset(&self, tick_size: f64, sign: f64) -> f64 {
+        let outside = match self {
+            TickDir::Outside => tick_size,
+            TickDir::Inside => 0.,
+        };
+        sign * super::REM.max(outside + super::REM / 4.)
+    }
+}
@@ -0,0 +1,535 @@
+use theta_chart::{
+    coord::{Cartesian, Vector},
+    series::Series,
+};
+
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "Sparkline"
+))]
+use super::LineKind;
+
+#[cfg(any(doc, feature = "LineChart"))]
+use super::{GapMode, Marker, Orientation};
+
+#[cfg(any(
+    doc,
+    feature = "DonutChart",
+    feature = "GaugeChart",
+    feature = "PieChart"
+))]
+use {
+    super::point_on_circle,
+    std::f64::consts::{PI, TAU},
+};
+
+/// Scale the points of a line to pixels
+///
+/// The i-th point is made of the i-th values of `xseries` and `yseries`,
+/// scaled to the vector of the region of the chart. Pure function, no rendering involved.
+pub fn build_line_points(xseries: &Series, yseries: &Series, vector: &Vector) -> Vec<(f64, f64)> {
+    let ysticks = yseries.to_stick();
+    xseries
+        .to_stick()
+        .iter()
+        .zip(ysticks)
+        .map(|(x, y)| {
+            (
+                xseries.scale(x.value) * vector.get_x(),
+                yseries.scale(y.value) * vector.get_y(),
+            )
+        })
+        .collect()
+}
+
+/// Position in pixels of a point of a [`Cartesian`], e.g. to place HTML over the chart
+///
+/// The position accounts for the translations of the chart in its SVG, by the margin and the
+/// origin of the region of the chart, and for the scaling of its values, as drawn by
+/// `LineChart` and `ScatterChart` with their default scales. It is relative to the top-left
+/// corner of the SVG when it has no title and its pixel size (`fixed_size=true`).
+///
+/// # Examples
+/// ```ignore
+/// let chart = Cartesian::new(
+///     Series::from(vec![1.0, 6.0, 9.]),
+///     Series::from(vec![1.0, 3.0, 5.]),
+/// )
+/// .set_view(820, 620, 3, 100, 100, 20);
+/// if let Some((x, y)) = chart.point_pixel(1) {
+///     let style = format!("position:absolute;left:{}px;top:{}px", x, y);
+/// }
+///
+/// // The point under a click, within 10 pixels, at the same position in pixels
+/// let index = chart.nearest_point(x, y, Some(10.));
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait PointPixel {
+    /// Position `(x, y)` in pixels of the point at `index`, none beyond the points
+    fn point_pixel(&self, index: usize) -> Option<(f64, f64)>;
+
+    /// Positions `(x, y)` in pixels of all the points, scaled at once
+    fn point_pixels(&self) -> Vec<(f64, f64)>;
+
+    /// Index of the point the closest to the position `(x, y)` in pixels, e.g. for a tooltip
+    /// or a click handler, the lowest index between points at the same distance
+    ///
+    /// None without points, or when the closest is farther than `max_distance` pixels.
+    /// The missing values (NaN) are never the closest.
+    fn nearest_point(&self, x: f64, y: f64, max_distance: Option<f64>) -> Option<usize> {
+        self.point_pixels()
+            .into_iter()
+            .enumerate()
+            .map(|(index, (px, py))| (index, (px - x).hypot(py - y)))
+            .filter(|(_, distance)| !distance.is_nan())
+            .fold(
+                None,
+                |nearest: Option<(usize, f64)>, (index, distance)| match nearest {
+                    Some((_, nearest_distance)) if nearest_distance <= distance => nearest,
+                    _ => Some((index, distance)),
+                },
+            )
+            .filter(|(_, distance)| max_distance.map_or(true, |max| *distance <= max))
+            .map(|(index, _)| index)
+    }
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl PointPixel for Cartesian {
+    fn point_pixel(&self, index: usize) -> Option<(f64, f64)> {
+        let (series_x, series_y) = (self.get_ax(), self.get_ay());
+        let x = series_x.to_stick().get(index)?.value;
+        let y = series_y.to_stick().get(index)?.value;
+        let view = self.get_view();
+        let (margin, rec_chart) = (view.get_margin(), view.get_rec_chart());
+        let (origin, vector) = (rec_chart.get_origin(), rec_chart.get_vector());
+        Some((
+            margin + origin.get_x() + series_x.scale(x) * vector.get_x(),
+            margin + origin.get_y() + series_y.scale(y) * vector.get_y(),
+        ))
+    }
+
+    fn point_pixels(&self) -> Vec<(f64, f64)> {
+        let view = self.get_view();
+        let (margin, rec_chart) = (view.get_margin(), view.get_rec_chart());
+        let origin = rec_chart.get_origin();
+        build_line_points(&self.get_ax(), &self.get_ay(), &rec_chart.get_vector())
+            .into_iter()
+            .map(|(x, y)| (margin + origin.get_x() + x, margin + origin.get_y() + y))
+            .collect()
+    }
+}
+
+/// Build the path (`M x,y ...`) of the straight line through the points of the series
+///
+/// See [`build_line_points`] for the scaling of the points.
+///
+/// # Examples
+/// ```ignore
+/// let path = build_line_path(
+///     &Series::from(vec![0., 1., 2.]),
+///     &Series::from(vec![0., 2., 4.]),
+///     &Vector::new(100., -100.),
+/// );
+/// assert_eq!(path, "M 0,0  50,-50  100,-100 ");
+/// ```
+pub fn build_line_path(xseries: &Series, yseries: &Series, vector: &Vector) -> String {
+    gen_linear(&build_line_points(xseries, yseries, vector), 0)
+}
+
+// Generate the path of a line passing through the points (in pixel),
+// with `precision` decimals for the coordinates
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "Sparkline"
+))]
+pub(crate) fn gen_line_path(points: &[(f64, f64)], kind: LineKind, precision: usize) -> String {
+    match kind {
+        LineKind::StepBefore | LineKind::StepAfter if !points.is_empty() => {
+            return gen_step(points, kind == LineKind::StepBefore, precision);
+        }
+        _ if points.len() < 3 => return gen_linear(points, precision),
+        _ => (),
+    }
+    match kind {
+        LineKind::Monotone => gen_monotone(points, precision),
+        LineKind::CatmullRom => gen_catmull_rom(points, precision),
+        _ => gen_linear(points, precision),
+    }
+}
+
+// Points of a line with the missing values (NaN) at `zero` for `GapMode::Zero`, else as is
+#[cfg(any(doc, feature = "LineChart"))]
+pub(crate) fn fill_gaps(
+    points: Vec<(f64, f64)>,
+    gaps: GapMode,
+    zero: (f64, f64),
+) -> Vec<(f64, f64)> {
+    if gaps != GapMode::Zero {
+        return points;
+    }
+    points
+        .into_iter()
+        .map(|(x, y)| {
+            let x = if x.is_nan() { zero.0 } else { x };
+            let y = if y.is_nan() { zero.1 } else { y };
+            (x, y)
+        })
+        .collect()
+}
+
+// Runs of the indices of the points drawn as one line, without the missing values (NaN):
+// broken at them for `GapMode::Skip`, else over them
+#[cfg(any(doc, feature = "LineChart"))]
+pub(crate) fn gap_runs(points: &[(f64, f64)], gaps: GapMode) -> Vec<Vec<usize>> {
+    let mut runs = vec![vec![]];
+    for (index, (x, y)) in points.iter().enumerate() {
+        if x.is_finite() && y.is_finite() {
+            if let Some(run) = runs.last_mut() {
+                run.push(index);
+            }
+        } else if gaps == GapMode::Skip && runs.last().is_some_and(|run| !run.is_empty()) {
+            runs.push(vec![]);
+        }
+    }
+    runs.retain(|run| !run.is_empty());
+    runs
+}
+
+// Indices of the points kept by the largest-triangle-three-buckets downsampling to at most
+// `max_points` (at least the first and last), all of them without limit or below it.
+// The points are split in buckets, keeping in each the one which makes the largest triangle
+// with the point kept before and the average of the next bucket, so the shape is preserved.
+#[cfg(any(doc, feature = "LineChart"))]
+pub(crate) fn decimate(points: &[(f64, f64)], max_points: Option<usize>) -> Vec<usize> {
+    let count = points.len();
+    let max_points = match max_points {
+        Some(max_points) if max_points.max(2) < count => max_points.max(2),
+        _ => return (0..count).collect(),
+    };
+    let buckets = max_points - 2;
+    let every = (count - 2) as f64 / buckets as f64;
+    let bucket = |index: usize| {
+        let start = (index as f64 * every) as usize + 1;
+        let end = (((index + 1) as f64 * every) as usize + 1).min(count - 1);
+        start..end.max(start + 1)
+    };
+
+    let mut kept = Vec::with_capacity(max_points);
+    kept.push(0);
+    for index in 0..buckets {
+        // Average of the next bucket, the last point for the last bucket
+        let next = if index + 1 < buckets {
+            bucket(index + 1)
+        } else {
+            count - 1..count
+        };
+        let len = next.len() as f64;
+        let (avg_x, avg_y) = points[next].iter().fold((0., 0.), |(x, y), point| {
+            (x + point.0 / len, y + point.1 / len)
+        });
+        let (a_x, a_y) = points[kept[kept.len() - 1]];
+        let area =
+            |&(x, y): &(f64, f64)| ((a_x - avg_x) * (y - a_y) - (a_x - x) * (avg_y - a_y)).abs();
+        let best = bucket(index)
+            .max_by(|&i, &j| area(&points[i]).total_cmp(&area(&points[j])))
+            .unwrap_or_default();
+        kept.push(best);
+    }
+    kept.push(count - 1);
+    kept
+}
+
+// Close the path of a line down to the baseline (in pixel) to fill the area under it
+#[cfg(any(doc, feature = "LineChart"))]
+pub(crate) fn gen_area_path(
+    line: &str,
+    points: &[(f64, f64)],
+    baseline: f64,
+    orientation: Orientation,
+    precision: usize,
+) -> String {
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return String::default(),
+    };
+    let (end, start) = match orientation {
+        Orientation::Horizontal => ((last.0, baseline), (first.0, baseline)),
+        Orientation::Vertical => ((baseline, last.1), (baseline, first.1)),
+    };
+    format!(
+        "{} L {} L {} Z",
+        line,
+        gen_coords(end, precision),
+        gen_coords(start, precision)
+    )
+}
+
+// Generate the points of the polygon of a marker centered at (x, y), none for other shapes.
+// The triangle is centered at its centroid, a third of its height above its base.
+#[cfg(any(doc, feature = "LineChart"))]
+pub(crate) fn gen_marker_points(marker: Marker, x: f64, y: f64, r: f64) -> Option<String> {
+    let corners = match marker {
+        Marker::Triangle => {
+            let (top, base) = (y - 4. * r / 3., y + 2. * r / 3.);
+            vec![(x, top), (x + r, base), (x - r, base)]
+        }
+        Marker::Diamond => vec![(x, y - r), (x + r, y), (x, y + r), (x - r, y)],
+        Marker::Circle | Marker::Square => return None,
+    };
+    let points = corners
+        .into_iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<_>>();
+    Some(points.join(" "))
+}
+
+// Generate the path of a slice of a ring centered at (0, 0), angles in radians clockwise
+// from the top. The inner radius 0 gives a slice of a pie, a full sweep gives a full ring.
+#[cfg(any(
+    doc,
+    feature = "DonutChart",
+    feature = "GaugeChart",
+    feature = "PieChart"
+))]
+pub(crate) fn gen_ring_path(start: f64, sweep: f64, radius: f64, inner_radius: f64) -> String {
+    if sweep >= TAU - 1e-9 {
+        // A single arc from a point to itself is not drawn, so split the circles in halves
+        let circle = |r: f64, sweep_flag: i32| {
+            format!(
+                "M 0,{} A {},{} 0 1,{} 0,{} A {},{} 0 1,{} 0,{} Z ",
+                -r, r, r, sweep_flag, r, r, r, sweep_flag, -r
+            )
+        };
+        let mut path = circle(radius, 1);
+        if inner_radius > 0. {
+            path.push_str(&circle(inner_radius, 0));
+        }
+        return path;
+    }
+
+    let end = start + sweep;
+    let large = (sweep > PI) as i32;
+    let (x1, y1) = point_on_circle(radius, start);
+    let (x2, y2) = point_on_circle(radius, end);
+    let mut path = format!(
+        "M {},{} A {},{} 0 {},1 {},{} ",
+        x1, y1, radius, radius, large, x2, y2
+    );
+    if inner_radius > 0. {
+        let (x3, y3) = point_on_circle(inner_radius, end);
+        let (x4, y4) = point_on_circle(inner_radius, start);
+        path.push_str(&format!(
+            "L {},{} A {},{} 0 {},0 {},{} Z",
+            x3, y3, inner_radius, inner_radius, large, x4, y4
+        ));
+    } else {
+        path.push_str("L 0,0 Z");
+    }
+    path
+}
+
+// Coordinates `x,y` of a point of a path, with `precision` decimals
+fn gen_coords((x, y): (f64, f64), precision: usize) -> String {
+    // Rounded first, for the values rounding to zero not to be written -0
+    let factor = 10_f64.powi(precision as i32);
+    let round = |value: f64| (value * factor).round() / factor + 0.;
+    format!("{:.*},{:.*}", precision, round(x), precision, round(y))
+}
+
+fn gen_linear(points: &[(f64, f64)], precision: usize) -> String {
+    let mut line = "M".to_string();
+    for &point in points.iter() {
+        line.push_str(format!(" {} ", gen_coords(point, precision)).as_str());
+    }
+    line
+}
+
+// Horizontal and vertical segments between the points, the vertical one first when `before`
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "Sparkline"
+))]
+fn gen_step(points: &[(f64, f64)], before: bool, precision: usize) -> String {
+    let mut line = format!("M {}", gen_coords(points[0], precision));
+    for &(x, y) in points.iter().skip(1) {
+        let step = if before {
+            format!(" V {:.*} H {:.*}", precision, y, precision, x)
+        } else {
+            format!(" H {:.*} V {:.*}", precision, x, precision, y)
+        };
+        line.push_str(&step);
+    }
+    line
+}
+
+// Cubic Bezier from the tangents at both ends of each segment
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "Sparkline"
+))]
+fn gen_cubic(points: &[(f64, f64)], tangents: &[f64], precision: usize) -> String {
+    let mut line = format!("M {}", gen_coords(points[0], precision));
+    for index in 0..points.len() - 1 {
+        let (x0, y0) = points[index];
+        let (x1, y1) = points[index + 1];
+        let h = (x1 - x0) / 3.;
+        line.push_str(
+            format!(
+                " C {} {} {}",
+                gen_coords((x0 + h, y0 + tangents[index] * h), precision),
+                gen_coords((x1 - h, y1 - tangents[index + 1] * h), precision),
+                gen_coords((x1, y1), precision),
+            )
+            .as_str(),
+        );
+    }
+    line
+}
+
+// Monotone cubic interpolation (Fritsch-Carlson, like d3 curveMonotoneX)
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "Sparkline"
+))]
+fn gen_monotone(points: &[(f64, f64)], precision: usize) -> String {
+    let len = points.len();
+    let slopes = (0..len - 1)
+        .map(|index| {
+            let dx = points[index + 1].0 - points[index].0;
+            let dy = points[index + 1].1 - points[index].1;
+            if dx == 0. {
+                0.
+            } else {
+                dy / dx
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut tangents = vec![0.; len];
+    tangents[0] = slopes[0];
+    tangents[len - 1] = slopes[len - 2];
+    for index in 1..len - 1 {
+        let (s0, s1) = (slopes[index - 1], slopes[index]);
+        let h0 = points[index].0 - points[index - 1].0;
+        let h1 = points[index + 1].0 - points[index].0;
+        if s0 * s1 <= 0. || h0 + h1 == 0. {
+            continue;
+        }
+        let p = (s0 * h1 + s1 * h0) / (h0 + h1);
+        tangents[index] = (s0.signum() + s1.signum()) * s0.abs().min(s1.abs()).min(0.5 * p.abs());
+    }
+    gen_cubic(points, &tangents, precision)
+}
+
+// Uniform Catmull-Rom spline converted to cubic Bezier
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "Sparkline"
+))]
+fn gen_catmull_rom(points: &[(f64, f64)], precision: usize) -> String {
+    let len = points.len();
+    let mut line = format!("M {}", gen_coords(points[0], precision));
+    for index in 0..len - 1 {
+        let p0 = points[index.saturating_sub(1)];
+        let p1 = points[index];
+        let p2 = points[index + 1];
+        let p3 = points[(index + 2).min(len - 1)];
+        line.push_str(
+            format!(
+                " C {} {} {}",
+                gen_coords(
+                    (p1.0 + (p2.0 - p0.0) / 6., p1.1 + (p2.1 - p0.1) / 6.),
+                    precision
+                ),
+                gen_coords(
+                    (p2.0 - (p3.0 - p1.0) / 6., p2.1 - (p3.1 - p1.1) / 6.),
+                    precision
+                ),
+                gen_coords(p2, precision),
+            )
+            .as_str(),
+        );
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Three points on the diagonal of a region 100 pixels wide and high, y upward
+    fn series() -> (Series, Series, Vector) {
+        (
+            Series::from(vec![0., 1., 2.]),
+            Series::from(vec![0., 2., 4.]),
+            Vector::new(100., -100.),
+        )
+    }
+
+    #[test]
+    fn build_line_points_scales_to_the_vector() {
+        let (xseries, yseries, vector) = series();
+        assert_eq!(
+            build_line_points(&xseries, &yseries, &vector),
+            vec![(0., 0.), (50., -50.), (100., -100.)]
+        );
+    }
+
+    #[test]
+    fn build_line_path_of_three_points() {
+        let (xseries, yseries, vector) = series();
+        assert_eq!(
+            build_line_path(&xseries, &yseries, &vector),
+            "M 0,0  50,-50  100,-100 "
+        );
+    }
+
+    #[test]
+    fn nearest_point_of_the_pixels() {
+        let chart = Cartesian::new(
+            Series::from(vec![1.0, 6.0, 9.]),
+            Series::from(vec![1.0, 3.0, 5.]),
+        )
+        .set_view(820, 620, 3, 100, 100, 20);
+        let pixels = chart.point_pixels();
+        assert_eq!(
+            pixels,
+            (0..3)
+                .filter_map(|index| chart.point_pixel(index))
+                .collect::<Vec<_>>()
+        );
+
+        let (x, y) = pixels[1];
+        assert_eq!(chart.nearest_point(x + 3., y - 4., None), Some(1));
+        assert_eq!(chart.nearest_point(x + 3., y - 4., Some(5.)), Some(1));
+        assert_eq!(chart.nearest_point(x + 3., y - 4., Some(4.)), None);
+    }
+
+    #[test]
+    #[cfg(any(doc, feature = "LineChart"))]
+    fn triangle_marker_centered_at_its_centroid() {
+        // The mean of the corners is the point of the marker, (10, 20)
+        let points = gen_marker_points(Marker::Triangle, 10., 20., 3.);
+        assert_eq!(points.as_deref(), Some("10,16 13,22 7,22"));
+        assert_eq!(gen_marker_points(Marker::Circle, 10., 20., 3.), None);
+    }
+}
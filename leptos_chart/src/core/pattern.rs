@@ -0,0 +1,27 @@
+use leptos::{view, IntoView};
+use theta_chart::color::Color;
+
+// Side of the tile of a pattern
+const TILE: f64 = 8.;
+
+// Count of the distinct patterns, cycled by the index of the series
+pub(crate) const PATTERNS: usize = 4;
+
+// Pattern of the color with white marks, by `index`: diagonal lines, dots, crosshatch
+// and horizontal lines, in the coordinates of the elements using it (e.g. a line)
+pub(crate) fn pattern_fill(id: String, color: &Color, index: usize) -> impl IntoView {
+    let marks = match index % PATTERNS {
+        0 => view! { <path d="M -2,2 l 4,-4 M 0,8 l 8,-8 M 6,10 l 4,-4"></path> }.into_view(),
+        1 => view! { <circle cx=TILE / 2. cy=TILE / 2. r=1.5 fill="#ffffff"></circle> }.into_view(),
+        2 => view! { <path d="M 0,0 l 8,8 M 0,8 l 8,-8"></path> }.into_view(),
+        _ => view! { <path d="M 0,4 h 8"></path> }.into_view(),
+    };
+    view! {
+      <pattern id=id patternUnits="userSpaceOnUse" width=TILE height=TILE>
+        <rect width=TILE height=TILE fill=color.to_string_hex()></rect>
+        <g stroke="#ffffff" stroke-width="1.5">
+          {marks}
+        </g>
+      </pattern>
+    }
+}
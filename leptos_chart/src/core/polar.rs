@@ -0,0 +1,65 @@
+use std::f64::consts::TAU;
+use theta_chart::coord::Polar;
+
+/// Angular and radial scales of the values of a [`Polar`], shared by the polar charts
+///
+/// The angles are in radians, clockwise from the top of the circle like the slices of
+/// `PieChart`; see [`point_on_circle`] for their points.
+///
+/// # Examples
+/// ```ignore
+/// let chart = Polar::new(Series::from(vec![1.0, 3.0]), Series::from(vec!["A", "B"]));
+/// assert_eq!(chart.scale_angle(1.0), PI / 2.);
+/// assert_eq!(chart.angles(), vec![(0., PI / 2.), (PI / 2., 2. * PI)]);
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait PolarScale {
+    /// Angle of the part of the total of the values taken by `value`, zero for a zero total
+    fn scale_angle(&self, value: f64) -> f64;
+
+    /// Start and end angles of the slice of each value, starting where the previous ended
+    fn angles(&self) -> Vec<(f64, f64)>;
+
+    /// Part of the radius for `value`, from zero at the center to one for the largest value
+    fn scale_radius(&self, value: f64) -> f64;
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl PolarScale for Polar {
+    fn scale_angle(&self, value: f64) -> f64 {
+        let total = self.get_data().series().iter().sum::<f64>();
+        if total == 0. {
+            return 0.;
+        }
+        value / total * TAU
+    }
+
+    fn angles(&self) -> Vec<(f64, f64)> {
+        let mut start = 0.;
+        self.get_data()
+            .series()
+            .into_iter()
+            .map(|value| {
+                let end = start + self.scale_angle(value);
+                let angles = (start, end);
+                start = end;
+                angles
+            })
+            .collect()
+    }
+
+    fn scale_radius(&self, value: f64) -> f64 {
+        let max = self.get_data().series().into_iter().fold(0., f64::max);
+        if max == 0. {
+            return 0.;
+        }
+        value / max
+    }
+}
+
+/// Point `(x, y)` at `angle` on the circle of `radius` around the origin, in the coordinates
+/// of the SVG (y down): the angle is in radians, clockwise from the top
+#[cfg(any(doc, feature = "core"))]
+pub fn point_on_circle(radius: f64, angle: f64) -> (f64, f64) {
+    (radius * angle.sin(), -radius * angle.cos())
+}
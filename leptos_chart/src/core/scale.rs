@@ -0,0 +1,409 @@
+use theta_chart::{
+    chart::{ScaleNumber, ScaleTime},
+    coord::Cartesian,
+    series::Series,
+};
+
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "HeatmapChart",
+    feature = "LineChart"
+))]
+use theta_chart::coord::{Point, Rec, Vector};
+
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+use theta_chart::coord::{Axes, Stick};
+
+#[cfg(any(doc, feature = "BarChart", feature = "BarChartGroup"))]
+use {super::Sort, theta_chart::series::SLabel};
+
+#[cfg(any(doc, feature = "LineChart"))]
+use super::ChartError;
+
+// Region with its vertical direction reversed, from its other edge: the scaled values and
+// the sticks of the axes placed in it go from top to bottom
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "HeatmapChart",
+    feature = "LineChart"
+))]
+pub(crate) fn reverse_vertical(region: &Rec) -> Rec {
+    let (origin, vector) = (region.get_origin(), region.get_vector());
+    Rec::new(
+        Point::new(origin.get_x(), origin.get_y() + vector.get_y()),
+        Vector::new(vector.get_x(), -vector.get_y()),
+    )
+}
+
+// Region mirrored in a view of the given width, from its other edge: the scaled values and
+// the sticks of the axes placed in it go from right to left
+#[cfg(any(doc, feature = "LineChart"))]
+pub(crate) fn mirror_horizontal(region: &Rec, width: f64) -> Rec {
+    let (origin, vector) = (region.get_origin(), region.get_vector());
+    Rec::new(
+        Point::new(width - origin.get_x(), origin.get_y()),
+        Vector::new(-vector.get_x(), vector.get_y()),
+    )
+}
+
+// Transform a number series in log10 space, with the sticks of the axes at the powers of ten
+#[cfg(any(doc, feature = "LineChart"))]
+pub(crate) fn log10_series(series: &Series) -> Result<(Series, Axes), ChartError> {
+    let values = match series {
+        Series::Number(_) => series.to_stick().into_iter().map(|stick| stick.value),
+        _ => {
+            return Err(ChartError::UnsupportedSeries(
+                "The logarithmic scale needs a series of numbers".to_string(),
+            ))
+        }
+    };
+    let mut logs: Vec<f64> = vec![];
+    for value in values {
+        if value <= 0. {
+            return Err(ChartError::OutOfRange(format!(
+                "The logarithmic scale needs positive values, got {}",
+                value
+            )));
+        }
+        logs.push(value.log10());
+    }
+
+    let min = logs.iter().copied().fold(f64::INFINITY, f64::min).floor() as i32;
+    let mut max = logs
+        .iter()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max)
+        .ceil() as i32;
+    if max <= min {
+        max = min + 1;
+    }
+    let range = (max - min) as f64;
+
+    let sticks = (min..=max)
+        .map(|power| {
+            let label = format!("{}", 10_f64.powi(power));
+            Stick::new(label, (power - min) as f64 / range)
+        })
+        .collect::<Vec<_>>();
+
+    Ok((
+        Series::from(logs).set_range(min as f64, max as f64),
+        Axes::new(sticks, 1., "log".to_string()),
+    ))
+}
+
+// Extend the range of a number series to include zero, other series are unchanged
+#[cfg(any(doc, feature = "LineChart", feature = "RadarChartGroup"))]
+pub(crate) fn include_zero(series: &Series) -> Series {
+    match series {
+        Series::Number(number) => {
+            let (min, max) = number.domain();
+            series.set_range(min.min(0.), max.max(0.))
+        }
+        _ => series.clone(),
+    }
+}
+
+/// Round bounds and step of an axis covering `min` to `max`, with about `ticks` sticks
+///
+/// The step is 1, 2 or 5 times a power of ten and the bounds are multiples of the step,
+/// e.g. to set the range of a series with round ends.
+///
+/// # Examples
+/// ```ignore
+/// assert_eq!(nice_range(3.7, 97.1, 6), (0., 100., 20.));
+///
+/// let (min, max, _) = nice_range(3.7, 97.1, 6);
+/// let series = Series::from(vec![3.7, 27.1, 97.1]).set_range(min, max);
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub fn nice_range(min: f64, max: f64, ticks: usize) -> (f64, f64, f64) {
+    let range = if max > min { max - min } else { 1. };
+    // The count of sticks includes both bounds
+    let rough = range / (ticks.max(2) - 1) as f64;
+    let magnitude = 10_f64.powf(rough.log10().floor());
+    // The step giving the count of sticks nearest to the one asked
+    let count = |step: f64| (max / step).ceil() - (min / step).floor() + 1.;
+    let step = [1., 2., 5., 10.]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .min_by(|a, b| {
+            let distance = |step: f64| (count(step) - ticks as f64).abs();
+            distance(*a).total_cmp(&distance(*b))
+        })
+        .unwrap_or(magnitude);
+    let low = (min / step).floor() * step;
+    let high = ((max / step).ceil() * step).max(low + step);
+    (low, high, step)
+}
+
+// Count of sticks of the round axes when not chosen, as the automatic axes of theta_chart
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+const NICE_TICKS: usize = 11;
+
+// Extend a number series to round bounds, with about `ticks` sticks at steps of 1, 2 or 5
+// times a power of ten; other series are unchanged, with their own sticks
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+pub(crate) fn ticks_series(series: &Series, ticks: usize) -> (Series, Axes) {
+    let Series::Number(number) = series else {
+        return (series.clone(), series.gen_axes());
+    };
+    let (min, max) = number.domain();
+    let (low, high, step) = nice_range(min, max, ticks);
+    let precision = (-step.log10().floor()).max(0.) as usize;
+
+    let count = ((high - low) / step).round() as i64;
+    let sticks = (0..=count)
+        .map(|index| {
+            let value = low + index as f64 * step;
+            let label = format!("{:.prec$}", value, prec = precision);
+            Stick::new(label, (value - low) / (high - low))
+        })
+        .collect::<Vec<_>>();
+
+    (
+        series.set_range(low, high),
+        Axes::new(sticks, step, "number".to_string()),
+    )
+}
+
+// Series and sticks of an axis: at round bounds with `ticks` or `nice`, see ticks_series,
+// the automatic ones of theta_chart otherwise
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+pub(crate) fn nice_series(series: &Series, ticks: Option<usize>, nice: bool) -> (Series, Axes) {
+    match (ticks, nice) {
+        (Some(ticks), _) => ticks_series(series, ticks),
+        (None, true) => ticks_series(series, NICE_TICKS),
+        (None, false) => (series.clone(), series.gen_axes()),
+    }
+}
+
+/// Inverse of the `scale` of a [`Series`], e.g. to get the value under the pointer
+///
+/// # Examples
+/// ```ignore
+/// let series = Series::from(vec![0., 5., 10.]);
+/// assert_eq!(series.unscale(series.scale(2.5)), 2.5);
+///
+/// // The category of a label series at the position, from its left edge to its right one
+/// let labels = Series::from(vec!["A", "B", "C"]);
+/// assert_eq!(labels.unscale(0.4), 1.);
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait Unscale {
+    /// The value at the position `fraction`, 0 and 1 being the ends of the domain
+    ///
+    /// For a label series, the index of the category spanning the position, the one
+    /// at the position given by [`Positions`] for its center. For a time series, the time
+    /// in the unit of the series, e.g. the year for the unit `year`.
+    fn unscale(&self, fraction: f64) -> f64;
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl Unscale for Series {
+    fn unscale(&self, fraction: f64) -> f64 {
+        match self {
+            Series::Number(number) => {
+                let (min, max) = number.domain();
+                min + fraction * (max - min)
+            }
+            Series::Label(_) => {
+                let count = self.get_count();
+                (fraction * count as f64)
+                    .floor()
+                    .clamp(0., count.saturating_sub(1) as f64)
+            }
+            Series::Time(time) => {
+                let (min, max) = time.domain_unix();
+                min + fraction * (max - min)
+            }
+        }
+    }
+}
+
+/// Positions of the sticks of a [`Series`], e.g. to place the elements of a custom chart
+///
+/// # Examples
+/// ```ignore
+/// let series = Series::from(vec![0., 5., 10.]);
+/// assert_eq!(series.positions(), vec![0., 0.5, 1.]);
+/// assert_eq!(series.pixel_positions(200.), vec![0., 100., 200.]);
+///
+/// // The centers of the categories of a label series
+/// let labels = Series::from(vec!["A", "B"]);
+/// assert_eq!(labels.positions(), vec![0.25, 0.75]);
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait Positions {
+    /// The position of every stick of `to_stick`, 0 and 1 being the ends of the domain
+    ///
+    /// For a label series, the center of each category, as the sticks of its axis.
+    fn positions(&self) -> Vec<f64>;
+
+    /// The positions multiplied by `length`, e.g. the x of the sticks along a region
+    /// of this width in pixels, negative when the region goes to the left or the top
+    fn pixel_positions(&self, length: f64) -> Vec<f64> {
+        self.positions()
+            .into_iter()
+            .map(|position| position * length)
+            .collect()
+    }
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl Positions for Series {
+    fn positions(&self) -> Vec<f64> {
+        let center = match self {
+            Series::Label(_) => 0.5,
+            _ => 0.,
+        };
+        self.to_stick()
+            .into_iter()
+            .map(|stick| self.scale(stick.value + center))
+            .collect()
+    }
+}
+
+/// Fixed bounds of the axes of a [`Cartesian`], e.g. for several small charts to share
+/// the same scale, instead of the range computed from their data
+///
+/// The bounds are the range of the number series, see `Series::set_range`, used by its
+/// `gen_axes` and `scale`; its values, sticks and format are unchanged, so the tooltips and
+/// [`ToCsv`](crate::ToCsv) give the data. The range of an axis of theta_chart always
+/// includes the data: a value out of the bounds extends it. The series of labels or times
+/// are unchanged.
+///
+/// The charts with `nice_axis` (`LineChart`, `BarChartGroup`) still round bounds which are
+/// not round, e.g. 3 to 97 to 0 to 100; set `nice_axis=false` to keep them as is.
+///
+/// # Examples
+/// ```ignore
+/// let chart = Cartesian::new(
+///     Series::from(vec![1.0, 2.0, 3.]),
+///     Series::from(vec![12.0, 48.0, 30.]),
+/// )
+/// .set_y_bounds(0., 100.)
+/// .set_view(820, 620, 3, 100, 100, 20);
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait SetBounds: Sized {
+    /// Range of the x axis from `min` to `max`, in any order
+    fn set_x_bounds(self, min: f64, max: f64) -> Self;
+
+    /// Range of the y axis from `min` to `max`, in any order
+    fn set_y_bounds(self, min: f64, max: f64) -> Self;
+}
+
+// Number series with the range from min to max, unchanged when a bound is not finite
+fn bounded(series: Series, min: f64, max: f64) -> Series {
+    if matches!(series, Series::Number(_)) && min.is_finite() && max.is_finite() {
+        series.set_range(min.min(max), min.max(max))
+    } else {
+        series
+    }
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl SetBounds for Cartesian {
+    fn set_x_bounds(self, min: f64, max: f64) -> Self {
+        let ax = bounded(self.get_ax(), min, max);
+        self.set_ax(ax)
+    }
+
+    fn set_y_bounds(self, min: f64, max: f64) -> Self {
+        self.set_ay(bounded(self.get_ay(), min, max))
+    }
+}
+
+// Labels of a series ordered by their values, summed over the pairs (labels, values)
+// of the series of a group; the series of numbers or times are unchanged
+#[cfg(any(doc, feature = "BarChart", feature = "BarChartGroup"))]
+pub(crate) fn sort_labels(labels: &Series, pairs: &[(Series, Series)], sort: Sort) -> Series {
+    if sort == Sort::None || !matches!(labels, Series::Label(_)) {
+        return labels.clone();
+    }
+    let mut sums = labels
+        .to_stick()
+        .into_iter()
+        .map(|stick| (stick.label, 0.))
+        .collect::<Vec<(String, f64)>>();
+    for (series_labels, series_values) in pairs {
+        for (label, value) in series_labels
+            .to_stick()
+            .into_iter()
+            .zip(series_values.to_stick())
+        {
+            if let Some(sum) = sums.iter_mut().find(|sum| sum.0 == label.label) {
+                sum.1 += value.value;
+            }
+        }
+    }
+    match sort {
+        Sort::Ascending => sums.sort_by(|a, b| a.1.total_cmp(&b.1)),
+        Sort::Descending => sums.sort_by(|a, b| b.1.total_cmp(&a.1)),
+        Sort::None => (),
+    }
+    Series::Label(SLabel::from(
+        sums.into_iter().map(|sum| sum.0).collect::<Vec<_>>(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(any(feature = "LineChart", feature = "RadarChartGroup"))]
+    fn include_zero_grows_the_bars_from_zero() {
+        let series = include_zero(&Series::from(vec![-0.7, 1.5, -1.9]));
+        let Series::Number(number) = &series else {
+            panic!("not a number series");
+        };
+        let (min, max) = number.domain();
+        assert!(min <= 0. && 0. <= max);
+
+        // The baseline of the bars is at the scaled zero, between the negative and positive bars
+        let vector = theta_chart::coord::Vector::new(100., -100.);
+        let baseline = series.scale(0.) * vector.get_y();
+        assert!((baseline - -100. * 1.9 / 3.4).abs() < 1e-9);
+        assert!(series.scale(1.5) * vector.get_y() < baseline);
+        assert!(series.scale(-0.7) * vector.get_y() > baseline);
+        assert!(series.scale(-1.9) * vector.get_y() > baseline);
+    }
+
+    #[test]
+    fn unscale_the_positions_of_labels() {
+        let labels = Series::from(vec!["A", "B", "C", "D"]);
+        for (index, position) in labels.positions().into_iter().enumerate() {
+            assert_eq!(labels.unscale(position), index as f64);
+        }
+        assert_eq!((labels.unscale(0.), labels.unscale(1.)), (0., 3.));
+    }
+
+    #[test]
+    fn unscale_a_time_series_to_its_unit() {
+        let years = Series::from((vec!["1990", "2000", "2020"], "%Y", "year"));
+        assert_eq!((years.unscale(0.), years.unscale(1.)), (1990., 2020.));
+        assert_eq!(years.unscale(1. / 3.), 2000.);
+    }
+
+    #[test]
+    fn bounded_keeps_the_data() {
+        let chart = Cartesian::new(
+            Series::from(vec![1.0, 2.0, 3.]),
+            Series::from(vec![12.0, 48.0, 30.]),
+        )
+        .set_x_bounds(0., 4.)
+        .set_y_bounds(100., 0.);
+        let number = |series: Series| match series {
+            Series::Number(number) => number,
+            _ => panic!("not a number series"),
+        };
+        assert_eq!(number(chart.get_ax()).domain(), (0., 4.));
+        assert_eq!(number(chart.get_ay()).domain(), (0., 100.));
+        assert_eq!(number(chart.get_ay()).series(), vec![12., 48., 30.]);
+        assert_eq!(chart.get_ay().scale(48.), 0.48);
+    }
+}
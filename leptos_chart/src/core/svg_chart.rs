@@ -1,30 +1,333 @@
+use crate::core::{
+    class_name, provide_class_prefix, provide_debug, ChartTheme, DebugView, Dir, REM,
+};
 use leptos::*;
-use theta_chart::coord::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use theta_chart::{color::Color, coord::*};
 
-// Wrap chart in SVG
+// Count of the clip paths of the page, for their ids to be unique over several charts
+static CLIP_PATHS: AtomicUsize = AtomicUsize::new(0);
+
+// Clip path of the region of a chart, provided by its SVG wrapper to the elements inside
+#[derive(Clone)]
+#[cfg(any(
+    doc,
+    feature = "BarChart",
+    feature = "BarChartGroup",
+    feature = "BubbleChart",
+    feature = "CandlestickChart",
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "LineChartGroup",
+    feature = "ScatterChart",
+    feature = "StackedAreaChart",
+    feature = "StackedBarChart",
+    feature = "WaterfallChart"
+))]
+struct ClipPath(Option<String>);
+
+// Reference to the clip path of the region of the chart, for the `clip-path` of its data
+#[cfg(any(
+    doc,
+    feature = "BarChart",
+    feature = "BarChartGroup",
+    feature = "BubbleChart",
+    feature = "CandlestickChart",
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChart",
+    feature = "LineChartGroup",
+    feature = "ScatterChart",
+    feature = "StackedAreaChart",
+    feature = "StackedBarChart",
+    feature = "WaterfallChart"
+))]
+pub(crate) fn clip_path() -> Option<String> {
+    use_context::<ClipPath>().and_then(|ClipPath(id)| id.map(|id| format!("url(#{})", id)))
+}
+
+/// Layer of a [`SvgChart`] drawn below its children, e.g. bands behind the data
+#[cfg(any(doc, feature = "core"))]
+#[slot]
+pub struct Underlay {
+    /// Elements of the layer
+    children: ChildrenFn,
+}
+
+/// Layer of a [`SvgChart`] drawn above its children, e.g. tooltips over the data
+#[cfg(any(doc, feature = "core"))]
+#[slot]
+pub struct Overlay {
+    /// Elements of the layer
+    children: ChildrenFn,
+}
+
+/// Component SvgChart for leptos, the SVG wrapping the Cartesian charts
+///
+/// A building block for new chart types: it sizes the SVG for the view, draws the titles
+/// and the backgrounds, and holds the layers of the chart in this order, from the bottom:
+/// the optional [`Underlay`], the children and the optional [`Overlay`].
+/// All of them are in the coordinates of the view, inside its margin.
+///
+/// # Examples
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn MyChart(chart: Cartesian) -> impl IntoView {
+///     view! {
+///         <SvgChart cview=chart.get_view()>
+///             <Underlay slot>
+///                 <rect width="100" height="50" fill="#eeeeee"></rect>
+///             </Underlay>
+///             <circle cx="50" cy="25" r="10"></circle>
+///             <Overlay slot>
+///                 <text x="50" y="25">"label"</text>
+///             </Overlay>
+///         </SvgChart>
+///     }
+/// }
+/// ```
+///
+/// The other props are the ones forwarded by the charts, e.g. `title`, `background`,
+/// `theme`, `dir` or `debug`, documented with [`LineChart`](crate::LineChart). The `region`
+/// of the plot background and of the clip is the region of the chart of the view, unless
+/// given, e.g. when the chart is shrunk for its legend or mirrored.
+///
 #[cfg(any(doc, feature = "core"))]
 #[component]
-pub fn SvgChart(cview: CView, children: Children) -> impl IntoView {
+pub fn SvgChart(
+    cview: CView,
+    #[prop(optional)] node_ref: NodeRef<svg::Svg>,
+    #[prop(default = false)] responsive: bool,
+    #[prop(default = false)] fixed_size: bool,
+    #[prop(default = None)] title: Option<String>,
+    #[prop(default = None)] subtitle: Option<String>,
+    #[prop(default = 1.25 * REM)] title_size: f64,
+    #[prop(default = 0.875 * REM)] subtitle_size: f64,
+    #[prop(default = None)] aria_label: Option<String>,
+    #[prop(default = None)] desc: Option<String>,
+    #[prop(default = None)] class_prefix: Option<String>,
+    #[prop(default = true)] clip: bool,
+    #[prop(default = None)] on_keydown: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(default = None)] background: Option<Color>,
+    #[prop(default = None)] plot_background: Option<Color>,
+    #[prop(default = None)] region: Option<Rec>,
+    #[prop(default = None)] theme: Option<ChartTheme>,
+    #[prop(default = Dir::Ltr)] dir: Dir,
+    #[prop(default = false)] debug: bool,
+    #[prop(default = None)] debug_color: Option<Color>,
+    #[prop(optional)] underlay: Option<Underlay>,
+    #[prop(optional)] overlay: Option<Overlay>,
+    children: Children,
+) -> impl IntoView {
+    provide_class_prefix(class_prefix);
+    provide_debug(debug, debug_color);
     let margin = cview.get_margin();
 
     let translate_chart = format!("translate({},{})", margin, margin);
     let vec_chart = cview.get_vector();
-    let view_box = format!("0 0 {} {}", vec_chart.get_x(), vec_chart.get_y());
-    view! {
-      <svg class="chart" viewBox=view_box>
 
-        {#[cfg(feature = "debug")]
-        {
-            view! {
-              <rect width=vec_chart.get_x() height=vec_chart.get_y() fill="#005bbe11"></rect>
+    // The titles are drawn in a space reserved above the view of the chart
+    let height_title = title.as_ref().map_or(0., |_| 1.5 * title_size);
+    let height_subtitle = subtitle.as_ref().map_or(0., |_| 1.5 * subtitle_size);
+    let height_titles = height_title + height_subtitle;
+    let view_box = format!(
+        "0 {} {} {}",
+        -height_titles,
+        vec_chart.get_x(),
+        vec_chart.get_y() + height_titles
+    );
+
+    // Responsive: scale to the width of the container, fixed: the pixel size of the view,
+    // else only the viewBox, sized by the CSS of the page
+    let (width, height) = if responsive {
+        (Some("100%".to_string()), None)
+    } else if fixed_size {
+        (
+            Some(vec_chart.get_x().to_string()),
+            Some((vec_chart.get_y() + height_titles).to_string()),
+        )
+    } else {
+        (None, None)
+    };
+    let aspect_ratio = responsive.then_some("xMidYMid meet");
+
+    // For theme: the background unless given, the color and the font of the texts
+    let background =
+        background.or_else(|| theme.as_ref().and_then(|theme| theme.background.clone()));
+    let (text_color, font) = theme.map_or((None, (None, None)), |theme| {
+        (
+            theme.axis_color.map(|color| color.to_string_hex()),
+            (theme.font_family, theme.font_size),
+        )
+    });
+
+    // Clip at the region of the chart, in its coordinates, with room for the markers at its edges
+    let clip_id = clip.then(|| {
+        format!(
+            "leptos-chart-clip-{}",
+            CLIP_PATHS.fetch_add(1, Ordering::Relaxed)
+        )
+    });
+    #[cfg(any(
+        doc,
+        feature = "BarChart",
+        feature = "BarChartGroup",
+        feature = "BubbleChart",
+        feature = "CandlestickChart",
+        feature = "ComboChart",
+        feature = "DualAxisLineChart",
+        feature = "LineChart",
+        feature = "LineChartGroup",
+        feature = "ScatterChart",
+        feature = "StackedAreaChart",
+        feature = "StackedBarChart",
+        feature = "WaterfallChart"
+    ))]
+    provide_context(ClipPath(clip_id.clone()));
+    let rec_region = region.unwrap_or_else(|| cview.get_rec_chart());
+    let vec_region = rec_region.get_vector();
+
+    // Focusable when it handles the keys, its content is then exposed as a group, not an image
+    let (role, tabindex) = match on_keydown {
+        Some(_) => ("group", Some("0")),
+        None => ("img", None),
+    };
+    view! {
+      <svg
+        class=class_name("chart")
+        viewBox=view_box
+        width=width
+        height=height
+        preserveAspectRatio=aspect_ratio
+        role=role
+        tabindex=tabindex
+        aria-label=aria_label.clone()
+        color=text_color.clone()
+        fill=text_color.map(|_| "currentColor")
+        font-family=font.0
+        font-size=font.1
+        direction=dir.attribute()
+        node_ref=node_ref
+        on:keydown=move |event| {
+            if let Some(on_keydown) = on_keydown {
+                on_keydown.call(event);
             }
-        }}
+        }
+      >
+        // For accessibility: name and description of the chart
+        {aria_label.map(|label| svg::title().child(label))}
+        {desc.map(|desc| svg::desc().child(desc))}
+
+        // Backgrounds first, for everything else to be drawn on top
+        {background
+            .map(|color| {
+                view! {
+                  <rect
+                    class=class_name("background")
+                    y=-height_titles
+                    width=vec_chart.get_x()
+                    height=vec_chart.get_y() + height_titles
+                    fill=color.to_string_hex()
+                  ></rect>
+                }
+            })}
+
+        <DebugView vector=vec_chart.clone()/>
 
-        <g class="inner-view" transform=translate_chart>
+        {title
+            .map(|title| {
+                view! {
+                  <text
+                    class=class_name("title")
+                    x=vec_chart.get_x() / 2.
+                    y=-height_titles + height_title / 2.
+                    text-anchor="middle"
+                    dominant-baseline="middle"
+                    font-size=title_size
+                    font-weight="bold"
+                  >
+                    {title}
+                  </text>
+                }
+            })}
+        {subtitle
+            .map(|subtitle| {
+                view! {
+                  <text
+                    class=class_name("subtitle")
+                    x=vec_chart.get_x() / 2.
+                    y=-height_subtitle / 2.
+                    text-anchor="middle"
+                    dominant-baseline="middle"
+                    font-size=subtitle_size
+                  >
+                    {subtitle}
+                  </text>
+                }
+            })}
 
+        <g class=class_name("inner-view") transform=translate_chart>
+          {plot_background
+              .map(|color| {
+                  let origin = rec_region.get_origin();
+                  view! {
+                    <rect
+                      class=class_name("plot-background")
+                      x=origin.get_x() + vec_region.get_x().min(0.)
+                      y=origin.get_y() + vec_region.get_y().min(0.)
+                      width=vec_region.get_x().abs()
+                      height=vec_region.get_y().abs()
+                      fill=color.to_string_hex()
+                    ></rect>
+                  }
+              })}
+
+          {clip_id
+              .map(|id| {
+                  view! {
+                    <defs>
+                      <clipPath id=id>
+                        <rect
+                          x=vec_region.get_x().min(0.) - REM / 2.
+                          y=vec_region.get_y().min(0.) - REM / 2.
+                          width=vec_region.get_x().abs() + REM
+                          height=vec_region.get_y().abs() + REM
+                        ></rect>
+                      </clipPath>
+                    </defs>
+                  }
+              })}
+
+          {underlay.map(|underlay| (underlay.children)())}
           {children()}
+          {overlay.map(|overlay| (overlay.children)())}
         </g>
 
       </svg>
     }
 }
+
+// Message centered in the region of a chart without data, e.g. "No data"
+#[component]
+pub(crate) fn EmptyMessage(region: Rec, message: Option<String>) -> impl IntoView {
+    let (origin, vector) = (region.get_origin(), region.get_vector());
+    message.map(|message| {
+        view! {
+          <text
+            class=class_name("empty-message")
+            x=origin.get_x() + vector.get_x() / 2.
+            y=origin.get_y() + vector.get_y() / 2.
+            text-anchor="middle"
+            dominant-baseline="middle"
+            fill="currentColor"
+            opacity="0.6"
+          >
+            {message}
+          </text>
+        }
+    })
+}
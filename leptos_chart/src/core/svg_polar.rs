@@ -1,25 +1,76 @@
+use crate::core::{class_name, provide_class_prefix, provide_debug, ChartTheme, DebugView};
 use leptos::*;
-use theta_chart::coord::*;
+use theta_chart::{color::Color, coord::*};
+
+// Region of the circle of a polar chart, e.g. to center the message of an error in it
+#[cfg(any(
+    doc,
+    feature = "DonutChart",
+    feature = "PieChart",
+    feature = "RadarChart"
+))]
+pub(crate) fn circle_region(pview: &PView) -> Rec {
+    let circle = pview.get_circle_chart();
+    let (center, radius) = (circle.get_origin(), circle.get_radius());
+    Rec::new(
+        Point::new(center.get_x() - radius, center.get_y() - radius),
+        Vector::new(2. * radius, 2. * radius),
+    )
+}
 
 // Wrap chart in SVG
 #[cfg(any(doc, feature = "core"))]
 #[component]
-pub fn SvgPolar(pview: PView, children: Children) -> impl IntoView {
+pub fn SvgPolar(
+    pview: PView,
+    #[prop(default = None)] class_prefix: Option<String>,
+    #[prop(default = None)] aria_label: Option<String>,
+    #[prop(default = None)] desc: Option<String>,
+    #[prop(default = None)] theme: Option<ChartTheme>,
+    #[prop(default = false)] debug: bool,
+    #[prop(default = None)] debug_color: Option<Color>,
+    children: Children,
+) -> impl IntoView {
+    provide_class_prefix(class_prefix);
+    provide_debug(debug, debug_color);
     let margin = pview.get_margin();
     let translate_chart = format!("translate({},{})", margin, margin);
     let vec_chart = pview.get_vector();
     let view_box = format!("0 0 {} {}", vec_chart.get_x(), vec_chart.get_y());
+    // For theme: the background, the color and the font of the texts
+    let theme = theme.unwrap_or_default();
+    let text_color = theme.axis_color.map(|color| color.to_string_hex());
     view! {
-      <svg class="chart" viewBox=view_box>
+      <svg
+        class=class_name("chart")
+        viewBox=view_box
+        role="img"
+        aria-label=aria_label.clone()
+        color=text_color.clone()
+        fill=text_color.map(|_| "currentColor")
+        font-family=theme.font_family
+        font-size=theme.font_size
+      >
+        // For accessibility: name and description of the chart
+        {aria_label.map(|label| svg::title().child(label))}
+        {desc.map(|desc| svg::desc().child(desc))}
+
+        {theme
+            .background
+            .map(|color| {
+                view! {
+                  <rect
+                    class=class_name("background")
+                    width=vec_chart.get_x()
+                    height=vec_chart.get_y()
+                    fill=color.to_string_hex()
+                  ></rect>
+                }
+            })}
 
-        {#[cfg(feature = "debug")]
-        {
-            view! {
-              <rect width=vec_chart.get_x() height=vec_chart.get_y() fill="#005bbe11"></rect>
-            }
-        }}
+        <DebugView vector=vec_chart.clone()/>
 
-        <g class="inner-view" transform=translate_chart>
+        <g class=class_name("inner-view") transform=translate_chart>
           {children()}
         </g>
       </svg>
@@ -0,0 +1,196 @@
+use theta_chart::color::Color;
+
+/// Shared styling of the charts, e.g. for a themed app
+///
+/// Given to the `theme` prop of a chart, it supplies the styling which the chart does not
+/// set otherwise: the props of the chart, e.g. `background` or `axis_color`, take precedence.
+/// The default theme changes nothing.
+///
+/// # Examples
+/// ```ignore
+/// let theme = ChartTheme {
+///     font_family: Some("Inter, sans-serif".to_string()),
+///     ..ChartTheme::dark()
+/// };
+///
+/// view!{
+///     <BarChartGroup chart=chart theme=theme.clone() />
+///     <PieChart chart=pie theme=theme />
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChartTheme {
+    /// Background of the whole SVG, transparent if none
+    pub background: Option<Color>,
+    /// Color of the axis lines, ticks and labels, and of the texts of the chart
+    pub axis_color: Option<Color>,
+    /// Color of the grid lines, drawn translucent
+    pub grid_color: Option<Color>,
+    /// Colors of the series (or slices), cycled, instead of shifting the hue of the color
+    pub palette: Vec<Color>,
+    /// Font family of the texts, e.g. `sans-serif`
+    pub font_family: Option<String>,
+    /// Font size of the texts in pixels
+    pub font_size: Option<f64>,
+}
+
+impl ChartTheme {
+    /// Dark texts on a white background, with the palette of Tableau 10
+    pub fn light() -> Self {
+        Self {
+            background: Some(Color::from("#ffffff")),
+            axis_color: Some(Color::from("#333333")),
+            grid_color: Some(Color::from("#000000")),
+            palette: palette(&[
+                "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2",
+                "#7f7f7f", "#bcbd22", "#17becf",
+            ]),
+            font_family: Some("sans-serif".to_string()),
+            font_size: None,
+        }
+    }
+
+    /// Light texts on a dark background, with a palette of bright colors
+    pub fn dark() -> Self {
+        Self {
+            background: Some(Color::from("#1e1e1e")),
+            axis_color: Some(Color::from("#dddddd")),
+            grid_color: Some(Color::from("#ffffff")),
+            palette: palette(&[
+                "#4fc3f7", "#ffb74d", "#81c784", "#e57373", "#ba68c8", "#fff176", "#4db6ac",
+                "#f06292", "#a1887f", "#90a4ae",
+            ]),
+            font_family: Some("sans-serif".to_string()),
+            font_size: None,
+        }
+    }
+}
+
+fn palette(colors: &[&str]) -> Vec<Color> {
+    colors.iter().map(|&color| Color::from(color)).collect()
+}
+
+// Saturation and lightness of the colors of a generated palette
+const PALETTE_SATURATION: f64 = 0.65;
+const PALETTE_LIGHTNESS: f64 = 0.5;
+
+/// Generated palettes of distinct colors, e.g. for the `palette` of a [`ChartTheme`] when
+/// the count of series is known, instead of shifting the hue by a fixed step which can give
+/// close colors for many series
+///
+/// # Examples
+/// ```ignore
+/// let theme = ChartTheme {
+///     palette: Color::palette(12),
+///     ..ChartTheme::light()
+/// };
+///
+/// // Also alternating darker and lighter colors, closer hues stay distinct
+/// let palette = Color::palette_jitter(24, 1.);
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait Palette: Sized {
+    /// `count` colors with their hues evenly spaced around the color wheel, from red,
+    /// at the same saturation and lightness
+    fn palette(count: usize) -> Vec<Self> {
+        Self::palette_jitter(count, 0.)
+    }
+
+    /// Same as [`palette`](Palette::palette), with the saturation and the lightness of the
+    /// colors alternated by `jitter` from 0 (none) to 1 (the most)
+    fn palette_jitter(count: usize, jitter: f64) -> Vec<Self>;
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl Palette for Color {
+    fn palette_jitter(count: usize, jitter: f64) -> Vec<Self> {
+        let jitter = if jitter.is_nan() {
+            0.
+        } else {
+            jitter.clamp(0., 1.)
+        };
+        (0..count)
+            .map(|index| {
+                let hue = 360. * index as f64 / count as f64;
+                // Every second color darker and more saturated, every third lighter
+                let (saturation, lightness) = match index % 3 {
+                    1 => (0.2, -0.15),
+                    2 => (-0.15, 0.15),
+                    _ => (0., 0.),
+                };
+                hsl_color(
+                    hue,
+                    PALETTE_SATURATION + jitter * saturation,
+                    PALETTE_LIGHTNESS + jitter * lightness,
+                )
+            })
+            .collect()
+    }
+}
+
+// Color of the hue in degrees, the saturation and the lightness from 0 to 1
+fn hsl_color(hue: f64, saturation: f64, lightness: f64) -> Color {
+    let chroma = (1. - (2. * lightness - 1.).abs()) * saturation;
+    let sector = hue.rem_euclid(360.) / 60.;
+    let second = chroma * (1. - (sector % 2. - 1.).abs());
+    let (red, green, blue) = match sector as u8 {
+        0 => (chroma, second, 0.),
+        1 => (second, chroma, 0.),
+        2 => (0., chroma, second),
+        3 => (0., second, chroma),
+        4 => (second, 0., chroma),
+        _ => (chroma, 0., second),
+    };
+    let light = lightness - chroma / 2.;
+    let component = |value: f64| ((value + light) * 255.).round().clamp(0., 255.) as u8;
+    Color::from(
+        format!(
+            "#{:02X}{:02X}{:02X}",
+            component(red),
+            component(green),
+            component(blue)
+        )
+        .as_str(),
+    )
+}
+
+// Color of the series at `index`: from the palette of the theme if any,
+// else the color with its hue shifted by index times `shift_degrees`
+#[cfg(any(
+    doc,
+    feature = "BarChartGroup",
+    feature = "BubbleChart",
+    feature = "ComboChart",
+    feature = "DonutChart",
+    feature = "DualAxisLineChart",
+    feature = "LineChartGroup",
+    feature = "PieChart",
+    feature = "RadarChartGroup",
+    feature = "StackedAreaChart",
+    feature = "StackedBarChart"
+))]
+pub(crate) fn series_color(
+    theme: &Option<ChartTheme>,
+    color: &Color,
+    shift_degrees: f32,
+    index: usize,
+) -> Color {
+    match theme {
+        Some(theme) if !theme.palette.is_empty() => {
+            theme.palette[index % theme.palette.len()].clone()
+        }
+        _ => color.shift_hue_degrees_index(shift_degrees, index),
+    }
+}
+
+// Color of the axes given to a chart, else the one of its theme
+#[cfg(any(doc, feature = "Axes"))]
+pub(crate) fn theme_axis_color(color: Option<Color>, theme: &Option<ChartTheme>) -> Option<Color> {
+    color.or_else(|| theme.as_ref().and_then(|theme| theme.axis_color.clone()))
+}
+
+// Color of the grid of the theme of a chart
+#[cfg(any(doc, feature = "BarChartGroup", feature = "LineChart"))]
+pub(crate) fn theme_grid_color(theme: &Option<ChartTheme>) -> Option<Color> {
+    theme.as_ref().and_then(|theme| theme.grid_color.clone())
+}
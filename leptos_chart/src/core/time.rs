@@ -0,0 +1,146 @@
+use super::ChartError;
+use chrono::{DateTime, Datelike, NaiveDate};
+use theta_chart::{
+    coord::{Axes, Stick},
+    series::Series,
+};
+
+// Maximum count of sticks on a time axis
+const MAX_TICKS: f64 = 10.;
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+const WEEK: i64 = 7 * DAY;
+// Average month, only used to choose the interval
+const MONTH: i64 = 2_629_746;
+
+// Interval between the sticks of a time axis
+#[derive(Clone, Copy)]
+enum Interval {
+    // Fixed duration in seconds, aligned on the Unix epoch
+    Seconds(i64),
+    // Calendar months, aligned on the first day of a month
+    Months(i32),
+}
+
+// Intervals from the smallest to the largest, with the format of their labels
+const INTERVALS: [(Interval, &str); 19] = [
+    (Interval::Seconds(MINUTE), "%H:%M"),
+    (Interval::Seconds(5 * MINUTE), "%H:%M"),
+    (Interval::Seconds(15 * MINUTE), "%H:%M"),
+    (Interval::Seconds(30 * MINUTE), "%H:%M"),
+    (Interval::Seconds(HOUR), "%H:%M"),
+    (Interval::Seconds(3 * HOUR), "%m-%d %H:%M"),
+    (Interval::Seconds(6 * HOUR), "%m-%d %H:%M"),
+    (Interval::Seconds(12 * HOUR), "%m-%d %H:%M"),
+    (Interval::Seconds(DAY), "%Y-%m-%d"),
+    (Interval::Seconds(2 * DAY), "%Y-%m-%d"),
+    (Interval::Seconds(WEEK), "%Y-%m-%d"),
+    (Interval::Months(1), "%Y-%m"),
+    (Interval::Months(3), "%Y-%m"),
+    (Interval::Months(6), "%Y-%m"),
+    (Interval::Months(12), "%Y"),
+    (Interval::Months(24), "%Y"),
+    (Interval::Months(60), "%Y"),
+    (Interval::Months(120), "%Y"),
+    (Interval::Months(1200), "%Y"),
+];
+
+impl Interval {
+    fn seconds(&self) -> i64 {
+        match self {
+            Interval::Seconds(seconds) => *seconds,
+            Interval::Months(months) => *months as i64 * MONTH,
+        }
+    }
+
+    // Timestamps of the sticks between min and max
+    fn ticks(&self, min: i64, max: i64) -> Vec<i64> {
+        match *self {
+            Interval::Seconds(step) => {
+                // The weeks begin on Monday, the epoch is a Thursday
+                let offset = if step == WEEK { -3 * DAY } else { 0 };
+                let first = (min - offset).div_euclid(step) * step + offset;
+                (0..)
+                    .map(|index| first + index * step)
+                    .skip_while(|tick| *tick < min)
+                    .take_while(|tick| *tick <= max)
+                    .collect()
+            }
+            Interval::Months(step) => {
+                let Some(date) = DateTime::from_timestamp(min, 0) else {
+                    return vec![];
+                };
+                let months = date.year() * 12 + date.month0() as i32;
+                let first = months.div_euclid(step) * step;
+                (0..)
+                    .map_while(|index| {
+                        let months = first + index * step;
+                        NaiveDate::from_ymd_opt(
+                            months.div_euclid(12),
+                            months.rem_euclid(12) as u32 + 1,
+                            1,
+                        )
+                        .and_then(|date| date.and_hms_opt(0, 0, 0))
+                        .map(|date| date.and_utc().timestamp())
+                    })
+                    .skip_while(|tick| *tick < min)
+                    .take_while(|tick| *tick <= max)
+                    .collect()
+            }
+        }
+    }
+}
+
+// Format a Unix timestamp in seconds (UTC)
+pub(crate) fn format_timestamp(timestamp: f64, format: &str) -> String {
+    DateTime::from_timestamp(timestamp as i64, 0).map_or(timestamp.to_string(), |date| {
+        date.format(format).to_string()
+    })
+}
+
+// Transform a number series of Unix timestamps (in seconds, UTC) in a series spanning
+// exactly the data, with the sticks of the axes at human-friendly dates
+pub(crate) fn time_series(series: &Series) -> Result<(Series, Axes), ChartError> {
+    let values = match series {
+        Series::Number(_) => series
+            .to_stick()
+            .into_iter()
+            .map(|stick| stick.value)
+            .collect::<Vec<_>>(),
+        _ => {
+            return Err(ChartError::UnsupportedSeries(
+                "The time axis needs a series of Unix timestamps".to_string(),
+            ))
+        }
+    };
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min) as i64;
+    let mut max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max) as i64;
+    if max <= min {
+        max = min + HOUR;
+    }
+    let range = (max - min) as f64;
+
+    let (interval, format) = INTERVALS
+        .iter()
+        .find(|(interval, _)| range / interval.seconds() as f64 <= MAX_TICKS)
+        .unwrap_or(&INTERVALS[INTERVALS.len() - 1]);
+    let sticks = interval
+        .ticks(min, max)
+        .into_iter()
+        .map(|tick| {
+            let label = format_timestamp(tick as f64, format);
+            Stick::new(label, (tick - min) as f64 / range)
+        })
+        .collect::<Vec<_>>();
+
+    Ok((
+        Series::from(values).set_range(min as f64, max as f64),
+        Axes::new(
+            sticks,
+            interval.seconds() as f64 / range,
+            "time".to_string(),
+        ),
+    ))
+}
@@ -0,0 +1,308 @@
+use super::ChartError;
+use std::mem;
+use theta_chart::{
+    coord::{Cartesian, CartesianGroup, Polar},
+    series::Series,
+};
+
+// Check that the series are not empty, the axes can not be computed otherwise
+pub(crate) fn check_series(series_x: &Series, series_y: &Series) -> Result<(), ChartError> {
+    if series_x.get_count() == 0 {
+        return Err(ChartError::EmptySeries("x"));
+    }
+    if series_y.get_count() == 0 {
+        return Err(ChartError::EmptySeries("y"));
+    }
+    Ok(())
+}
+
+// Check that the series have as many sticks, the points are drawn pairwise otherwise
+pub(crate) fn check_lengths(series_x: &Series, series_y: &Series) -> Result<(), ChartError> {
+    let (len_x, len_y) = (series_x.to_stick().len(), series_y.to_stick().len());
+    if len_x != len_y {
+        return Err(ChartError::MismatchedLengths { x: len_x, y: len_y });
+    }
+    Ok(())
+}
+
+// Check that the series have the same sticks, e.g. for the data sharing an x series
+#[cfg(any(
+    doc,
+    feature = "ComboChart",
+    feature = "DualAxisLineChart",
+    feature = "StackedAreaChart"
+))]
+pub(crate) fn same_series(first: &Series, other: &Series) -> bool {
+    let sticks = |series: &Series| {
+        series
+            .to_stick()
+            .into_iter()
+            .map(|stick| (stick.label, stick.value))
+            .collect::<Vec<_>>()
+    };
+    sticks(first) == sticks(other)
+}
+
+// Check that the polar chart has values, as many as its labels, the labels as the x series
+pub(crate) fn check_polar(chart: &Polar) -> Result<(), ChartError> {
+    let labels = Series::Label(chart.get_label());
+    let values = Series::Number(chart.get_data());
+    check_series(&labels, &values).and_then(|_| check_lengths(&labels, &values))
+}
+
+// Check that the values of a polar chart do not sum to zero, their parts of the circle can
+// not be computed otherwise
+#[cfg(any(doc, feature = "DonutChart", feature = "PieChart"))]
+pub(crate) fn check_total(chart: &Polar) -> Result<(), ChartError> {
+    if chart.get_data().series().iter().sum::<f64>() == 0. {
+        let message = "The values of the chart sum to zero".to_string();
+        return Err(ChartError::OutOfRange(message));
+    }
+    Ok(())
+}
+
+// Check that the value of a gauge is finite and that its range from min to max is not empty
+#[cfg(any(doc, feature = "GaugeChart"))]
+pub(crate) fn check_gauge(value: f64, min: f64, max: f64) -> Result<(), ChartError> {
+    if !value.is_finite() {
+        let message = format!("The value {} of the gauge is not finite", value);
+        return Err(ChartError::OutOfRange(message));
+    }
+    if min.is_nan() || max.is_nan() || max <= min {
+        let message = format!("The range of the gauge from {} to {} is empty", min, max);
+        return Err(ChartError::OutOfRange(message));
+    }
+    Ok(())
+}
+
+// Check that the group has data and that its series are not empty and of equal lengths
+pub(crate) fn check_group(chart: &CartesianGroup) -> Result<(), ChartError> {
+    let data = chart.get_data();
+    if data.is_empty() {
+        return Err(ChartError::NoData);
+    }
+    for (index, (series_x, series_y)) in data.iter().enumerate() {
+        check_series(series_x, series_y)
+            .and_then(|_| check_lengths(series_x, series_y))
+            .map_err(|error| ChartError::InGroup {
+                index,
+                error: Box::new(error),
+            })?;
+    }
+    Ok(())
+}
+
+/// Typed check of the data of a [`Cartesian`], a [`CartesianGroup`] or a [`Polar`], instead of
+/// comparing the message of `get_error` to an empty string
+///
+/// The components run the same check before drawing the data.
+///
+/// # Examples
+/// ```ignore
+/// match chart.check() {
+///     Ok(()) => view! { <LineChart chart=chart /> }.into_view(),
+///     Err(ChartError::EmptySeries(_)) => view! { <p>"No data yet"</p> }.into_view(),
+///     Err(error) => view! { <p>{error.to_string()}</p> }.into_view(),
+/// }
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait CheckChart {
+    /// Fails on empty series, series of different lengths or a group without data
+    fn check(&self) -> Result<(), ChartError>;
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl CheckChart for Cartesian {
+    fn check(&self) -> Result<(), ChartError> {
+        check_series(&self.get_ax(), &self.get_ay())
+            .and_then(|_| check_lengths(&self.get_ax(), &self.get_ay()))
+    }
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl CheckChart for CartesianGroup {
+    fn check(&self) -> Result<(), ChartError> {
+        check_group(self)
+    }
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl CheckChart for Polar {
+    fn check(&self) -> Result<(), ChartError> {
+        check_polar(self)
+    }
+}
+
+/// Checked construction of a [`Cartesian`], to handle the errors before rendering
+///
+/// # Examples
+/// ```ignore
+/// let chart = Cartesian::try_new(
+///     Series::from(vec![1.0, 2.0, 3.]),
+///     Series::from(vec![1.0, 4.0]),
+/// );
+/// assert_eq!(chart.unwrap_err(), ChartError::MismatchedLengths { x: 3, y: 2 });
+///
+/// let chart = Cartesian::try_new(Series::from(vec![1.0]), Series::from(vec![2.0]))?
+///     .try_set_view(820, 620, 3, 50, 50, 20)?;
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait TryCartesian: Sized {
+    /// Same as `new`, failing on empty series or series of different lengths
+    fn try_new(ax: Series, ay: Series) -> Result<Self, ChartError>;
+
+    /// Same as `set_view`, failing when the region of the chart would be empty
+    /// or the position of the origin is not 0, 1, 2 or 3
+    fn try_set_view(
+        self,
+        width: u64,
+        height: u64,
+        position_origin: usize,
+        height_x_axis: u64,
+        width_y_axis: u64,
+        margin: u64,
+    ) -> Result<Self, ChartError>;
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl TryCartesian for Cartesian {
+    fn try_new(ax: Series, ay: Series) -> Result<Self, ChartError> {
+        check_series(&ax, &ay)?;
+        check_lengths(&ax, &ay)?;
+        Ok(Cartesian::new(ax, ay))
+    }
+
+    fn try_set_view(
+        self,
+        width: u64,
+        height: u64,
+        position_origin: usize,
+        height_x_axis: u64,
+        width_y_axis: u64,
+        margin: u64,
+    ) -> Result<Self, ChartError> {
+        if position_origin > 3 {
+            return Err(ChartError::InvalidView(format!(
+                "the position of the origin is {}, not 0, 1, 2 or 3",
+                position_origin
+            )));
+        }
+        if width <= 2 * margin + width_y_axis {
+            return Err(ChartError::InvalidView(format!(
+                "the width {} leaves no room for the chart",
+                width
+            )));
+        }
+        if height <= 2 * margin + height_x_axis {
+            return Err(ChartError::InvalidView(format!(
+                "the height {} leaves no room for the chart",
+                height
+            )));
+        }
+        Ok(self.set_view(
+            width,
+            height,
+            position_origin,
+            height_x_axis,
+            width_y_axis,
+            margin,
+        ))
+    }
+}
+
+/// Construction of a [`CartesianGroup`] from [`Cartesian`]s, instead of repeated `add_data`
+///
+/// The axes of the group span the union of the series. With x series of labels, all the
+/// charts need the same labels, e.g. for the bars of a group to line up.
+/// The views of the charts are not kept, `set_view` the group.
+///
+/// # Examples
+/// ```ignore
+/// let chart = CartesianGroup::from_cartesians(vec![
+///     Cartesian::new(Series::from(vec!["A", "B"]), Series::from(vec![1.0, 4.0])),
+///     Cartesian::new(Series::from(vec!["A", "B"]), Series::from(vec![2.0, 3.0])),
+/// ])?
+/// .set_view(840, 640, 3, 50, 50, 20);
+/// ```
+#[cfg(any(doc, feature = "core"))]
+pub trait FromCartesians: Sized {
+    /// Group the data of the charts, failing on a chart without data, of different lengths,
+    /// or with x series of another kind or with other labels than the first chart
+    fn from_cartesians(charts: Vec<Cartesian>) -> Result<Self, ChartError>;
+}
+
+#[cfg(any(doc, feature = "core"))]
+impl FromCartesians for CartesianGroup {
+    fn from_cartesians(charts: Vec<Cartesian>) -> Result<Self, ChartError> {
+        let first = charts.first().ok_or(ChartError::NoData)?.get_ax();
+        let labels = |series: &Series| {
+            series
+                .to_stick()
+                .into_iter()
+                .map(|stick| stick.label)
+                .collect::<Vec<_>>()
+        };
+        let first_labels = labels(&first);
+        charts
+            .into_iter()
+            .enumerate()
+            .try_fold(CartesianGroup::new(), |group, (index, chart)| {
+                let (series_x, series_y) = (chart.get_ax(), chart.get_ay());
+                check_series(&series_x, &series_y)?;
+                check_lengths(&series_x, &series_y)?;
+                if mem::discriminant(&series_x) != mem::discriminant(&first) {
+                    return Err(ChartError::MismatchedKinds(index));
+                }
+                if matches!(series_x, Series::Label(_)) && labels(&series_x) != first_labels {
+                    return Err(ChartError::MismatchedLabels(index));
+                }
+                Ok(group.add_data(series_x, series_y))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_group_without_data() {
+        assert_eq!(check_group(&CartesianGroup::new()), Err(ChartError::NoData));
+    }
+
+    #[test]
+    fn check_lengths_of_different_lengths() {
+        let (series_x, series_y) = (
+            Series::from(vec![1.0, 2.0, 3.]),
+            Series::from(vec![1.0, 4.0]),
+        );
+        let error = ChartError::MismatchedLengths { x: 3, y: 2 };
+        assert_eq!(check_lengths(&series_x, &series_y), Err(error.clone()));
+        assert_eq!(Cartesian::try_new(series_x, series_y).err(), Some(error));
+    }
+
+    #[test]
+    #[cfg(any(doc, feature = "DonutChart", feature = "PieChart"))]
+    fn check_polar_of_values_summing_to_zero() {
+        let chart = Polar::new(Series::from(vec![0.0, 0.0]), Series::from(vec!["A", "B"]));
+        assert_eq!(check_polar(&chart), Ok(()));
+        assert!(matches!(
+            check_total(&chart),
+            Err(ChartError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(any(doc, feature = "GaugeChart"))]
+    fn check_gauge_of_an_empty_range() {
+        assert_eq!(check_gauge(50., 0., 100.), Ok(()));
+        assert!(matches!(
+            check_gauge(f64::NAN, 0., 100.),
+            Err(ChartError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            check_gauge(50., 100., 100.),
+            Err(ChartError::OutOfRange(_))
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+use crate::core::{
+    check_polar, check_total, circle_region, class_name, error_message, gen_ring_path,
+    report_error, series_color, ChartError, ChartTheme, DebugCircle, DebugRegion, EmptyMessage,
+    PolarScale, SvgPolar, REM,
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{color::Color, coord};
+
+/// Component DonutChart for leptos
+///
+/// Same as [`PieChart`](crate::PieChart), with a hole of `inner_radius` (fraction of the radius)
+/// in which the total of the values is written.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["DonutChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let chart = Polar::new(
+///         Series::from(vec![1.0, 2.0, 3.]),
+///         Series::from(vec!["A", "B", "C"])
+///     )
+///     .set_view(740, 540, 2, 200, 20);
+///
+///     let color = Color::from("#ff0000");
+///     let shift_degrees = 120.;
+///     
+///     view!{
+///         // color, shift_degrees and inner_radius are options
+///         <DonutChart chart=chart color=color shift_degrees=shift_degrees inner_radius=0.6 />
+///     }
+/// }
+/// ```
+/// ## Set view for DonutChart
+/// ```ignore
+///     ...
+///     .set_view(740, 540, 2, 200, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_label` : Positions for label
+/// - `len_label` : Distance for label
+/// - `margin` : Margin for actual chart
+///
+/// ## About position_label
+/// - Top: 0
+/// - Right: 1
+/// - Bottom: 2
+/// - Left: 3
+///
+/// ## About inner_radius
+///
+/// Radius of the hole as a fraction of the radius (default 0.5), 0 draws a pie.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the color of the texts and the font. The slices take the colors of
+/// its palette instead of shifting the hue of `color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart in `debug_color`
+/// (default green), e.g. to tune the parameters of `set_view` in a deployed build.
+/// The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn DonutChart(
+    chart: coord::Polar,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(default = 70.)] shift_degrees: f32,
+    #[prop(default = 0.5)] inner_radius: f64,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+) -> impl IntoView {
+    let pview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!("Donut chart of {} slices", chart.get_data().series().len());
+    if let Err(error) = check_polar(&chart).and_then(|_| check_total(&chart)) {
+        report_error(&error, on_error);
+        let region = circle_region(&pview);
+        return view! {
+          <SvgPolar
+            pview=pview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgPolar>
+        };
+    }
+
+    // For processing SNumber
+    let data = chart.get_data();
+    let series = data.series();
+    let total = series.iter().sum::<f64>();
+    // Angles of the slices (start, sweep)
+    let slices = chart
+        .angles()
+        .into_iter()
+        .map(|(start, end)| (start, end - start))
+        .collect::<Vec<_>>();
+
+    // For processing SLabel
+    let slabel = chart.get_label();
+
+    // For Chart
+    let circle_chart = pview.get_circle_chart();
+    let translate_chart = format!(
+        "translate({},{})",
+        circle_chart.get_origin().get_x(),
+        circle_chart.get_origin().get_y()
+    );
+
+    // For label
+    let rec_label = pview.get_rec_label();
+    let translate_label = format!(
+        "translate({},{})",
+        rec_label.get_origin().get_x(),
+        rec_label.get_origin().get_y(),
+    );
+
+    view! {
+      <SvgPolar
+        pview=pview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme.clone()
+      >
+
+        <g class=class_name("labels") transform=translate_label>
+          // For draw region of label
+
+          <DebugRegion vector=rec_label.get_vector()/>
+
+          {slabel
+              .labels()
+              .into_iter()
+              .enumerate()
+              .map(|(index, label)| {
+                  let color = series_color(&theme, &color, shift_degrees, index);
+                  let py = index as f64 * 1.5 * REM;
+                  view! {
+                    <text x=1.5 * REM y=py dominant-baseline="text-before-edge">
+                      {format!("{}: {}", label, series[index])}
+                    </text>
+                    <rect
+                      x=0
+                      y=py + (1.5 - 1.0) * REM / 2.
+                      width=REM
+                      height=REM
+                      fill=color.to_string_hex()
+                    ></rect>
+                  }
+              })
+              .collect::<Vec<_>>()}
+
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart>
+
+          <DebugCircle radius=circle_chart.get_radius()/>
+
+          {
+              let radius = circle_chart.get_radius();
+              let inner_radius = inner_radius.clamp(0., 1.) * radius;
+              let ring = slices
+                  .into_iter()
+                  .enumerate()
+                  .map(|(index, (start, sweep))| {
+                      let color = series_color(&theme, &color, shift_degrees, index);
+                      view! {
+                        <path
+                          fill=color.to_string_hex()
+                          stroke="#ffffff"
+                          stroke-width="1"
+                          d=gen_ring_path(start, sweep, radius, inner_radius)
+                        ></path>
+                      }
+                  })
+                  .collect::<Vec<_>>();
+              view! {
+                {ring}
+                {(inner_radius > 0.)
+                    .then(|| {
+                        view! {
+                          <text
+                            class=class_name("total")
+                            x=0
+                            y=0
+                            text-anchor="middle"
+                            dominant-baseline="middle"
+                            font-size=1.5 * REM
+                          >
+                            {total}
+                          </text>
+                        }
+                    })}
+              }
+          }
+
+        </g>
+      </SvgPolar>
+    }
+}
@@ -0,0 +1,245 @@
+use crate::core::{
+    check_gauge, class_name, error_message, gen_ring_path, report_error, ChartError, ChartTheme,
+    DebugCircle, EmptyMessage, SvgPolar, REM,
+};
+use leptos::{component, view, Callback, IntoView};
+use std::f64::consts::PI;
+use theta_chart::{
+    color::Color,
+    coord::{PView, Point, Rec, Vector},
+};
+
+/// Component GaugeChart for leptos
+///
+/// A semicircular gauge of a single value against the range from `min` to `max`:
+/// the arc of the value over the arc of the whole range, with the value written at its center.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["GaugeChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let thresholds = vec![
+///         (0., Color::from("#2ca02c")),
+///         (60., Color::from("#ff7f0e")),
+///         (85., Color::from("#d62728")),
+///     ];
+///
+///     view!{
+///         // min, max, color, thresholds, width and height are options
+///         <GaugeChart value=72. thresholds=thresholds label="CPU" />
+///     }
+/// }
+/// ```
+/// ## Arguments
+/// - `value` : The value, clamped to the range on the arc, written as is
+/// - `min`, `max` : The range of the gauge (default 0 to 100)
+/// - `width`, `height` : The size of the SVG (default 400 by 240)
+/// - `inner_radius` : Radius of the inside of the arc as a fraction of its radius (default 0.7)
+/// - `label` : Text written below the value, e.g. its unit (optional)
+/// - `value_format` : Function formatting the value, e.g. `|v| format!("{:.0}%", v)`
+///
+/// ## About thresholds
+///
+/// Optional zones `(from, color)` of the range, e.g. green from 0, amber from 60 and red from
+/// 85: the zones are drawn lighter on the arc of the range and the arc of the value takes the
+/// color of the zone of the value, else `color`.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the color of the texts and the font. Without thresholds, the arc of the value
+/// takes the first color of its palette instead of `color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart in `debug_color`
+/// (default green), e.g. to tune the parameters of `set_view` in a deployed build.
+/// The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn GaugeChart(
+    value: f64,
+    #[prop(default = 0.0)] min: f64,
+    #[prop(default = 100.0)] max: f64,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(optional)] thresholds: Vec<(f64, Color)>,
+    #[prop(default = 0.7)] inner_radius: f64,
+    #[prop(optional, into)] label: Option<String>,
+    #[prop(optional)] value_format: Option<fn(f64) -> String>,
+    #[prop(default = 400)] width: u64,
+    #[prop(default = 240)] height: u64,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+) -> impl IntoView {
+    let pview = PView::new(width, height, 0, 0, 0);
+    // For accessibility: a basic description of the data
+    let desc = format!("Gauge of the value {} from {} to {}", value, min, max);
+    if let Err(error) = check_gauge(value, min, max) {
+        report_error(&error, on_error);
+        let region = Rec::new(Point::new(0., 0.), Vector::new(width as f64, height as f64));
+        return view! {
+          <SvgPolar
+            pview=pview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgPolar>
+        };
+    }
+
+    // For arc: a half circle from the left, centered at the bottom of the room for the texts
+    let radius = (width as f64 / 2. - REM)
+        .min(height as f64 - 3. * REM)
+        .max(REM);
+    let inner_radius = inner_radius.clamp(0., 1.) * radius;
+    let translate_chart = format!("translate({},{})", width as f64 / 2., REM + radius);
+    let angle = |value: f64| -PI / 2. + (value.clamp(min, max) - min) / (max - min) * PI;
+
+    // For thresholds: the zones sorted by their start, to the start of the next one
+    let mut zones = thresholds
+        .into_iter()
+        .filter(|(from, _)| from.is_finite())
+        .collect::<Vec<_>>();
+    zones.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let zone_color = zones
+        .iter()
+        .rev()
+        .find(|(from, _)| *from <= value)
+        .map(|(_, color)| color.clone());
+    let value_color = zone_color.unwrap_or_else(|| {
+        theme
+            .as_ref()
+            .and_then(|theme| theme.palette.first().cloned())
+            .unwrap_or(color)
+    });
+    let zone_arcs = zones
+        .iter()
+        .enumerate()
+        .map(|(index, (from, color))| {
+            let to = zones.get(index + 1).map_or(max, |(to, _)| *to);
+            let (start, end) = (angle(*from), angle(to));
+            (start, end - start, color.to_string_hex())
+        })
+        .filter(|(_, sweep, _)| *sweep > 0.)
+        .collect::<Vec<_>>();
+
+    let text = value_format.map_or(value.to_string(), |format| format(value));
+    let format_bound =
+        move |bound: f64| value_format.map_or(bound.to_string(), |format| format(bound));
+    let sweep = angle(value) + PI / 2.;
+
+    view! {
+      <SvgPolar
+        pview=pview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+      >
+        <g class=class_name("inner-chart") transform=translate_chart>
+
+          <DebugCircle radius=radius/>
+
+          // For range: its arc, under its zones
+          <path
+            class=class_name("range")
+            d=gen_ring_path(-PI / 2., PI, radius, inner_radius)
+            fill="currentColor"
+            fill-opacity="0.1"
+          ></path>
+          <g class=class_name("zones") fill-opacity="0.3">
+            {zone_arcs
+                .into_iter()
+                .map(|(start, sweep, color)| {
+                    let d = gen_ring_path(start, sweep, radius, inner_radius);
+                    view! { <path d=d fill=color></path> }
+                })
+                .collect::<Vec<_>>()}
+          </g>
+
+          // For value: its arc from the minimum, when above it
+          {(sweep > 0.)
+              .then(|| {
+                  view! {
+                    <path
+                      class=class_name("value")
+                      d=gen_ring_path(-PI / 2., sweep, radius, inner_radius)
+                      fill=value_color.to_string_hex()
+                    ></path>
+                  }
+              })}
+
+          <text
+            class=class_name("value-text")
+            x=0
+            y=-0.25 * REM
+            text-anchor="middle"
+            font-size=2. * REM
+          >
+            {text}
+          </text>
+          {label
+              .map(|label| {
+                  view! {
+                    <text
+                      class=class_name("label")
+                      x=0
+                      y=0.25 * REM
+                      text-anchor="middle"
+                      dominant-baseline="text-before-edge"
+                      opacity=0.6
+                    >
+                      {label}
+                    </text>
+                  }
+              })}
+
+          // For bounds: below the ends of the arc
+          <g class=class_name("bounds") text-anchor="middle" dominant-baseline="text-before-edge">
+            <text x=-(radius + inner_radius) / 2. y=REM / 4.>
+              {format_bound(min)}
+            </text>
+            <text x=(radius + inner_radius) / 2. y=REM / 4.>
+              {format_bound(max)}
+            </text>
+          </g>
+
+        </g>
+      </SvgPolar>
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        check_series, class_name, error_message, mix, report_error, reverse_vertical,
+        theme_axis_color, ChartError, ChartTheme, DebugRegion, EmptyMessage, Scale, SvgChart,
+        TickDir,
+    },
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{color::Color, coord, series::Series};
+
+/// Component HeatmapChart for leptos
+///
+/// Draws a grid of cells, one per row and column, colored along a gradient by their values,
+/// e.g. for a correlation matrix or an activity calendar.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["HeatmapChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     // The labels of the columns and of the rows
+///     let chart = Cartesian::new(
+///         Series::from(vec!["Mon", "Tue", "Wed", "Thu", "Fri"]),
+///         Series::from(vec!["Morning", "Afternoon", "Evening"]),
+///     )
+///     .set_view(820, 420, 3, 50, 100, 20);
+///     let values = vec![
+///         vec![12., 18., 9., 14., 20.],
+///         vec![25., 30., 22., f64::NAN, 28.],
+///         vec![8., 6., 11., 7., 15.],
+///     ];
+///
+///     view!{
+///         // color_low, color_high and show_values are options
+///         <HeatmapChart chart=chart values=values show_values=true />
+///     }
+/// }
+/// ```
+/// ## Set view for HeatmapChart
+/// ```ignore
+///     ...
+///     .set_view(820, 420, 3, 100, 100, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis
+/// - `margin` : Margin for actual chart
+///
+/// ## About values
+///
+/// The `chart` has the labels of the columns on x and the ones of the rows on y,
+/// the `values` have one vector per row, from the top, with one value per column.
+/// The NaN values and the missing ones leave their cells blank.
+///
+/// ## About colors
+///
+/// The cells are colored from `color_low` (default light blue) for the smallest value to
+/// `color_high` (default blue) for the largest one, interpolated between them.
+///
+/// ## About color_scale
+///
+/// - `Scale::Linear` (default): the colors are interpolated along the values
+/// - `Scale::Log10`: the colors are interpolated along the log10 of the values, e.g. for
+///   counts spanning orders of magnitude; the zero or negative values have `color_low`
+///
+/// ## About show_values
+///
+/// Set `show_values=true` to write the value in each cell, formatted by the optional
+/// `value_format` (default: the number as is), in white on the cells of the darker half.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn HeatmapChart(
+    chart: coord::Cartesian,
+    values: Vec<Vec<f64>>,
+    #[prop(default = Color::from("#e3f0fb"))] color_low: Color,
+    #[prop(default = Color::default())] color_high: Color,
+    #[prop(default = Scale::Linear)] color_scale: Scale,
+    #[prop(default = false)] show_values: bool,
+    #[prop(optional)] value_format: Option<fn(f64) -> String>,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Heatmap of {} rows of {} cells",
+        values.len(),
+        values.first().map_or(0, |row| row.len())
+    );
+    let axis_color = theme_axis_color(None, &theme);
+    let (series_x, series_y) = (chart.get_ax(), chart.get_ay());
+    let checked = check_series(&series_x, &series_y).and_then(|_| match (&series_x, &series_y) {
+        (Series::Label(_), Series::Label(_)) => Ok(()),
+        _ => Err(ChartError::UnsupportedSeries(
+            "The heatmap chart needs series of labels on x and y".to_string(),
+        )),
+    });
+    if let Err(error) = checked {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For Chart: the rows from the top
+    let rec_chart = reverse_vertical(&cview.get_rec_chart());
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For x-axis
+    let rec_xa = cview.get_rec_x_axis();
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+    let axes_x = series_x.gen_axes();
+
+    // For y-axis: the rows from the top
+    let rec_ya = reverse_vertical(&cview.get_rec_y_axis());
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+    let axes_y = series_y.gen_axes();
+
+    // For colors: the values from the smallest to the largest, the NaN values left out,
+    // in log10 space with Scale::Log10 where only the positive values have a logarithm
+    let scaled = move |value: f64| match color_scale {
+        Scale::Linear => value,
+        Scale::Log10 if value > 0. => value.log10(),
+        Scale::Log10 => f64::NAN,
+    };
+    let (min, max) = values
+        .iter()
+        .flatten()
+        .map(|&value| scaled(value))
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    let amount = move |value: f64| match scaled(value) {
+        value if value.is_nan() => 0.,
+        value if max > min => (value - min) / (max - min),
+        _ => 0.5,
+    };
+    let format_value = move |value: f64| value_format.map_or(value.to_string(), |f| f(value));
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart>
+          // For draw region of chart
+
+          <DebugRegion vector=rec_chart.get_vector()/>
+
+          {
+              let vector = rec_chart.get_vector();
+              let (columns, rows) = (series_x.get_count(), series_y.get_count());
+              let width = vector.get_x() / columns as f64;
+              let height = vector.get_y() / rows as f64;
+              values
+                  .into_iter()
+                  .take(rows)
+                  .enumerate()
+                  .flat_map(|(row, values)| {
+                      values
+                          .into_iter()
+                          .take(columns)
+                          .enumerate()
+                          .filter(|(_, value)| value.is_finite())
+                          .map(move |(column, value)| (row, column, value))
+                  })
+                  .map(|(row, column, value)| {
+                      let (x, y) = (column as f64 * width, row as f64 * height);
+                      let amount = amount(value);
+                      let fill = mix(&color_low, &color_high, amount).to_string_hex();
+                      // Value at the center of the cell, readable on the darker cells
+                      let text_fill = if amount > 0.5 { "#ffffff" } else { "#000000" };
+                      let text = show_values
+                          .then(|| {
+                              view! {
+                                <text
+                                  class=class_name("value")
+                                  x=x + width / 2.
+                                  y=y + height / 2.
+                                  text-anchor="middle"
+                                  dominant-baseline="middle"
+                                  fill=text_fill
+                                >
+                                  {format_value(value)}
+                                </text>
+                              }
+                          });
+                      view! {
+                        <rect
+                          class=class_name("cell")
+                          x=x.min(x + width)
+                          y=y.min(y + height)
+                          width=width.abs()
+                          height=height.abs()
+                          fill=fill
+                        ></rect>
+                        {text}
+                      }
+                  })
+                  .collect::<Vec<_>>()
+          }
+
+        </g>
+      </SvgChart>
+    }
+}
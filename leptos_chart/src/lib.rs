@@ -3,21 +3,45 @@
 //! The project provides chart types to draw for leptos.
 //!
 //! - [x] PieChart
+//! - [x] DonutChart
+//! - [x] GaugeChart
 //! - [x] BarChart
 //! - [x] LineChart
 //! - [x] RadarChart
+//! - [x] RadarChartGroup
 //! - [x] ScatterChart
+//! - [x] BubbleChart
 //! - [x] BarChartGroup
+//! - [x] StackedBarChart
+//! - [x] StackedAreaChart
+//! - [x] WaterfallChart
+//! - [x] CandlestickChart
+//! - [x] HeatmapChart
 //! - [x] LineChartGroup
+//! - [x] Sparkline
+//! - [x] DualAxisLineChart
+//! - [x] ComboChart
 //! - [x] Voronoi
 //!
 //! ## Examples and Usage
 //! - [`PieChart`]
+//! - [`DonutChart`]
+//! - [`GaugeChart`]
 //! - [`BarChart`]
 //! - [`LineChart`]
+//! - [`Sparkline`]
 //! - [`RadarChart`]
+//! - [`RadarChartGroup`]
 //! - [`ScatterChart`]
+//! - [`BubbleChart`]
 //! - [`BarChartGroup`]
+//! - [`StackedBarChart`]
+//! - [`StackedAreaChart`]
+//! - [`DualAxisLineChart`]
+//! - [`ComboChart`]
+//! - [`WaterfallChart`]
+//! - [`CandlestickChart`]
+//! - [`HeatmapChart`]
 //! - [`Voronoi`]
 //!
 //! Check out the examples folder for helpful snippets of code, as well as minimal configurations that fit some of the most
@@ -33,6 +57,63 @@ pub use self::core::Series;
 #[cfg(any(doc, feature = "core"))]
 pub use self::core::Color;
 
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::Orientation;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::LineKind;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{LineSeriesStyle, LineStyle};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::Annotation;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::Marker;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{LineCap, LineJoin};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{Dir, TickDir, YAxisSide};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::LegendPos;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::Sort;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::GapMode;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::Scale;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{ChartTheme, Palette};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{download_svg, export_svg, ToCsv};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::Legend;
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{Overlay, SvgChart, Underlay};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{build_line_path, build_line_points, PointPixel};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{nice_range, Positions, SetBounds, Unscale};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{point_on_circle, PolarScale};
+
+#[cfg(any(doc, feature = "core"))]
+pub use self::core::{ChartError, CheckChart, FromCartesians, TryCartesian};
+
 #[cfg(any(doc, feature = "Axes"))]
 mod axes;
 
@@ -41,6 +122,16 @@ mod piechart;
 #[cfg(any(doc, feature = "PieChart"))]
 pub use self::piechart::*;
 
+#[cfg(any(doc, feature = "DonutChart"))]
+mod donutchart;
+#[cfg(any(doc, feature = "DonutChart"))]
+pub use self::donutchart::*;
+
+#[cfg(any(doc, feature = "GaugeChart"))]
+mod gaugechart;
+#[cfg(any(doc, feature = "GaugeChart"))]
+pub use self::gaugechart::*;
+
 #[cfg(any(doc, feature = "BarChart"))]
 mod barchart;
 #[cfg(any(doc, feature = "BarChart"))]
@@ -51,6 +142,31 @@ mod barchart_group;
 #[cfg(any(doc, feature = "BarChartGroup"))]
 pub use self::barchart_group::*;
 
+#[cfg(any(doc, feature = "StackedBarChart"))]
+mod barchart_stack;
+#[cfg(any(doc, feature = "StackedBarChart"))]
+pub use self::barchart_stack::*;
+
+#[cfg(any(doc, feature = "StackedAreaChart"))]
+mod areachart_stack;
+#[cfg(any(doc, feature = "StackedAreaChart"))]
+pub use self::areachart_stack::*;
+
+#[cfg(any(doc, feature = "WaterfallChart"))]
+mod waterfallchart;
+#[cfg(any(doc, feature = "WaterfallChart"))]
+pub use self::waterfallchart::*;
+
+#[cfg(any(doc, feature = "CandlestickChart"))]
+mod candlestickchart;
+#[cfg(any(doc, feature = "CandlestickChart"))]
+pub use self::candlestickchart::*;
+
+#[cfg(any(doc, feature = "HeatmapChart"))]
+mod heatmapchart;
+#[cfg(any(doc, feature = "HeatmapChart"))]
+pub use self::heatmapchart::*;
+
 #[cfg(any(doc, feature = "LineChart"))]
 mod linechart;
 #[cfg(any(doc, feature = "LineChart"))]
@@ -61,16 +177,41 @@ mod linechart_group;
 #[cfg(any(doc, feature = "LineChartGroup"))]
 pub use self::linechart_group::*;
 
+#[cfg(any(doc, feature = "Sparkline"))]
+mod sparkline;
+#[cfg(any(doc, feature = "Sparkline"))]
+pub use self::sparkline::*;
+
+#[cfg(any(doc, feature = "DualAxisLineChart"))]
+mod linechart_dual;
+#[cfg(any(doc, feature = "DualAxisLineChart"))]
+pub use self::linechart_dual::*;
+
+#[cfg(any(doc, feature = "ComboChart"))]
+mod combochart;
+#[cfg(any(doc, feature = "ComboChart"))]
+pub use self::combochart::*;
+
 #[cfg(any(doc, feature = "ScatterChart"))]
 mod scatterchart;
 #[cfg(any(doc, feature = "ScatterChart"))]
 pub use self::scatterchart::*;
 
+#[cfg(any(doc, feature = "BubbleChart"))]
+mod bubblechart;
+#[cfg(any(doc, feature = "BubbleChart"))]
+pub use self::bubblechart::*;
+
 #[cfg(any(doc, feature = "RadarChart"))]
 mod radarchart;
 #[cfg(any(doc, feature = "RadarChart"))]
 pub use self::radarchart::*;
 
+#[cfg(any(doc, feature = "RadarChartGroup"))]
+mod radarchart_group;
+#[cfg(any(doc, feature = "RadarChartGroup"))]
+pub use self::radarchart_group::*;
+
 #[cfg(any(doc, feature = "Voronoi"))]
 mod voronoi;
 #[cfg(any(doc, feature = "Voronoi"))]
@@ -1,9 +1,36 @@
 use crate::{
-    axes::{XAxis, YAxis},
-    core::SvgChart,
+    axes::{Grid, XAxis, YAxis},
+    core::{
+        build_line_points, check_lengths, check_series, class_name, clip_path, decimate,
+        error_message, fill_gaps, format_timestamp, gap_runs, gen_area_path, gen_line_path,
+        gen_marker_points, gradient_id, include_zero, lighten, linear_gradient, log10_series,
+        mirror_horizontal, mix, nice_series, report_error, reverse_vertical, theme_axis_color,
+        theme_grid_color, time_series, Annotation, ChartError, ChartTheme, DebugRegion, Dir,
+        EmptyMessage, GapMode, LineCap, LineJoin, LineKind, LineStyle, Marker, Orientation, Scale,
+        SvgChart, TickDir, Unscale, YAxisSide, REM,
+    },
 };
-use leptos::{component, view, IntoView};
-use theta_chart::{color::Color, coord};
+use leptos::{
+    component, create_node_ref, create_rw_signal,
+    ev::{mouseenter, mouseleave, KeyboardEvent, MouseEvent, WheelEvent},
+    html::{ElementDescriptor, HtmlElement},
+    store_value,
+    svg::{Rect, Svg},
+    view, Callable, Callback, IntoView, NodeRef, RwSignal, Signal, SignalGet, SignalGetUntracked,
+    SignalSet, SignalUpdate, StoredValue,
+};
+use theta_chart::{
+    color::Color,
+    coord,
+    coord::{Axes, Point, Rec, Vector},
+    series::Series,
+};
+
+// Maximum scale of the zoom
+const MAX_ZOOM: f64 = 20.;
+
+// Points in pixels of the runs of a line, kept to animate the next line from them
+type RunPoints = Vec<Vec<(f64, f64)>>;
 
 /// Component LineChart for leptos
 ///
@@ -31,9 +58,9 @@ use theta_chart::{color::Color, coord};
 ///     .set_view(820, 620, 3, 100, 100, 20);
 ///     let color = Color::from("#ff0000");
 ///
-///     // color is option
+///     // color and orientation are options
 ///     view!{
-///         <LineChart chart=chart color=color />
+///         <LineChart chart=chart color=color orientation=Orientation::Vertical />
 ///     }
 /// }
 /// ```
@@ -58,129 +85,1851 @@ use theta_chart::{color::Color, coord};
 /// - Bottom Right: 2
 /// - Bottom Left: 3
 ///
+/// ## About style
+///
+/// - `stroke_width` : Width of the line (default 2)
+/// - `style` : `LineStyle::Solid` (default), `LineStyle::Dashed` or `LineStyle::Dotted`
+/// - `line_join` : Corners of the line, `LineJoin::Miter` (default), `LineJoin::Round` or
+///   `LineJoin::Bevel`, round avoids the spikes at the sharp turns of jagged data
+/// - `line_cap` : Ends of the line, `LineCap::Butt` (default), `LineCap::Round` or
+///   `LineCap::Square`
+/// - `point_radius` : Radius of the point markers (default 2)
+/// - `point_color` : Fill color of the point markers (default red)
+/// - `show_points` : Draw the point markers (default true), disable for dense series
+/// - `point_labels` : Write the y value above each point, not clipped at the edges of the
+///   chart (default false), formatted by `point_label_format` (default: the number as is)
+/// - `label_every` : With `point_labels`, label only every n-th point of dense series,
+///   from the first one (default 1)
+/// - `marker` : Shape of the point markers, `Marker::Circle` (default), `Marker::Square`,
+///   `Marker::Triangle` or `Marker::Diamond`, sized by `point_radius`
+/// - `fill_area` : Fill the area between the line and the zero baseline (default false)
+/// - `gradient` : Fill the area with a gradient, from a lighter shade of `color` at the
+///   baseline to `color` (default false)
+/// - `color_series` : Values coloring the segments of the line, from `color_low` to `color`
+///   (optional)
+/// - `tooltips` : Show the x and y values when hovering a point marker (default false)
+/// - `crosshair` : Draw dashed lines following the pointer over the chart, with the
+///   nearest point highlighted (default false)
+/// - `shared_cursor` : Signal of the hovered x value, shared by the crosshairs of charts
+/// - `on_brush` : Callback with the range of the horizontal axis selected by a drag
+/// - `zoomable` : Zoom the data with the mouse wheel and pan them by a drag (default false)
+/// - `grid` : Draw grid lines at the sticks of the axes (default false)
+/// - `show_zero_line` : Emphasize the zero of the numeric axes when in their range,
+///   set apart from the grid (default false)
+/// - `x_title`, `y_title` : Titles of the horizontal and vertical axes (optional)
+/// - `h_lines` : Reference lines `(value, color)` at values of y, e.g. a target (optional),
+///   the values zero or negative are not drawn on a `Scale::Log10` axis
+/// - `bands` : Translucent bands `(from, to, color)` between values of y, e.g. a confidence
+///   interval, overlapping bands blend (optional)
+/// - `annotations` : Callouts with a text at points of the data (optional)
+/// - `x_tick_format`, `y_tick_format` : Callbacks formatting the numeric labels of the
+///   horizontal and vertical axes, e.g. `Callback::new(|v: f64| format!("${:.1}k", v / 1000.))`
+/// - `x_label_rotation` : Rotation in degrees of the labels of the horizontal axis,
+///   e.g. `-45.` for long labels which would overlap (default 0)
+/// - `x_label_max_chars` : Maximum count of characters of the labels of the horizontal axis,
+///   the longer ones end with an ellipsis and show the full label on hover (optional)
+/// - `x_label_wrap` : Wrap the long labels of the horizontal axis on two lines, split at
+///   a space (default false)
+/// - `axis_color` : Color of the axis lines, ticks and labels (optional, default currentColor)
+///
+/// ## About y_axis_side
+///
+/// Set `y_axis_side=YAxisSide::Right` to draw the y-axis at the right edge of a view
+/// with the origin on the left (`position_origin` 0 or 3), its labels on the right.
+///
+/// ## About y_reverse
+///
+/// Set `y_reverse=true` to reverse the vertical axis, the larger values at the bottom,
+/// e.g. for a ranking with the first at the top or a depth profile.
+///
+/// ## About dir
+///
+/// Set `dir=Dir::Rtl` for the locales written from right to left, e.g. Arabic or Hebrew:
+/// the chart is mirrored, the x-axis goes from right to left and the y-axis is on the right,
+/// the texts are in the direction `rtl` and ArrowLeft moves to the next point.
+///
+/// ## About node_ref
+///
+/// Optional reference to the rendered `<svg>`, e.g. to save the chart with
+/// [`export_svg`](crate::export_svg) and [`download_svg`](crate::download_svg),
+/// or to measure it with `get_bounding_client_rect()`.
+///
+/// ## About responsive
+///
+/// Set `responsive=true` to scale the chart to the width of its container,
+/// keeping the aspect ratio of `set_view`, or `fixed_size=true` to give the chart the
+/// pixel size of `set_view`; else the `<svg>` only has a `viewBox`, sized by its CSS.
+/// To lay out the chart again at the size of its container instead, with texts of the same
+/// size, measure the container, e.g. with a `ResizeObserver`, and give the measured size to
+/// `set_view` of the chart of [`LineChartReactive`](crate::LineChartReactive), as in the
+/// example `line_chart_resize`.
+///
+/// ## About title
+///
+/// Optional `title` and `subtitle`, centered in a space reserved above the chart,
+/// with the font sizes `title_size` (default 20) and `subtitle_size` (default 14).
+///
+/// ## About animate
+///
+/// Set `animate=true` to grow the line from the baseline when the chart is drawn,
+/// the duration is `animate_ms` (default 300 ms). Each time the chart of
+/// [`LineChartReactive`] changes, the line and its markers move from their previous
+/// positions to the new ones; the line grows from the baseline again when the count of
+/// its points (or of its segments for `gaps`) changes.
+///
+/// The previous points are kept in `previous_points`, which [`LineChartReactive`] gives to
+/// each `LineChart` it renders: pass the same `store_value(vec![])` to animate the updates
+/// of a `LineChart` of your own.
+///
+/// ## About y_scale
+///
+/// - `Scale::Linear` (default)
+/// - `Scale::Log10`: logarithmic y-axis, all values of the y series must be positive
+///
+/// The numeric linear axes are extended to round bounds, with their sticks at steps of 1, 2
+/// or 5 times a power of ten, see [`nice_range`](crate::nice_range): set `nice_axis=false`
+/// to keep the bounds of the data or of the range set on the series.
+/// Set `x_ticks` or `y_ticks` to choose about how many sticks such an axis has, instead of
+/// the automatic count, with round bounds in any case.
+///
+/// Set `y_begin_zero=true` to extend the y-axis to zero when a range without zero is set
+/// on the y series (linear scale only).
+///
+/// ## About clip
+///
+/// The data are clipped at the region of the chart, with a margin of 8 pixels for the
+/// markers at its edges, e.g. when a value is out of a fixed range. Set `clip=false`
+/// to draw them in full.
+///
+/// ## About class_prefix
+///
+/// Optional prefix of the classes of the elements, e.g. `class_prefix="myapp"` gives
+/// `myapp-chart`, `myapp-inner-chart`, `myapp-x-axis`, `myapp-line`, ... to style
+/// several charts of a page with different stylesheets.
+///
+/// ## About background
+///
+/// Optional `background` of the whole SVG and `plot_background` of the region of the chart,
+/// drawn behind the axes and the data, e.g. to match a themed card. The SVG is transparent
+/// otherwise.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes, the texts and the grid, and the font, unless set by
+/// the props. The line keeps its `color`, the palette is for the charts of several series.
+///
+/// ## About empty_message
+///
+/// Optional message centered in the chart when a series is empty, e.g.
+/// `empty_message="No data"`, instead of a blank chart.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About keyboard_nav
+///
+/// Set `keyboard_nav=true` to explore the points with the keyboard: the SVG is focusable
+/// (`tabindex="0"`, then with the `group` role), ArrowRight and ArrowLeft move to the
+/// next and previous points, the focused point is highlighted and its values are
+/// announced to screen readers by an `aria-live` region.
+///
+/// ## About x_time
+///
+/// Set `x_time=true` when the x series holds Unix timestamps (in seconds, UTC):
+/// the sticks of the axis are at human-friendly dates (minutes, hours, days, weeks,
+/// months or years, depending on the range). Being in UTC, the sticks are not shifted by DST.
+///
+/// ```ignore
+///     let chart = Cartesian::new(
+///         Series::from(vec![1700000000., 1700086400., 1700172800.]),
+///         Series::from(vec![1.0, 3.0, 5.])
+///     );
+///     view!{ <LineChart chart=chart x_time=true /> }
+/// ```
+///
+/// ## About kind
+///
+/// - `LineKind::Linear` (default): straight segments
+/// - `LineKind::Monotone`: smooth curve without overshoot for monotonic data
+/// - `LineKind::CatmullRom`: smooth curve through all points
+/// - `LineKind::StepBefore`: steps changing the value at the start of each interval
+/// - `LineKind::StepAfter`: steps keeping the value until the end of each interval
+///
+/// Set `coord_precision` to the count of decimals of the coordinates of the line
+/// (default 2): the sub-pixel coordinates give smoother lines, e.g. on small charts or when
+/// animating, `0` gives shorter paths for long series.
+///
+/// ## About color_series
+///
+/// Set `color_series` to encode a value along the line, e.g. the speed along a route:
+/// with one value per point, each segment is colored by the average of the values at
+/// its ends, interpolated from `color_low` (default a lighter shade of `color`)
+/// for the smallest value to `color` for the largest one. A segment with a missing value
+/// at its ends has the `color`.
+///
+/// The line is drawn as one path per segment, straight or in steps with `kind`, which is
+/// costly for large series: pair it with `max_points` to color the downsampled segments.
+///
+/// ## About max_points
+///
+/// Set `max_points` to draw at most this count of points for a large series, e.g.
+/// `max_points=Some(1000)` for high-frequency data: the line and its markers are downsampled
+/// by largest-triangle-three-buckets, which keeps the peaks and the shape of the line.
+/// The data are left intact, the crosshair and the keyboard navigation go through all of them.
+///
+/// ## About gaps
+///
+/// The missing values (`f64::NAN`) of the series are drawn by `gaps`:
+/// - `GapMode::Skip` (default): the line is broken at them, in separate segments
+/// - `GapMode::Connect`: the line connects the points around them
+/// - `GapMode::Zero`: they are drawn at zero
+///
+/// ## About shared_cursor
+///
+/// Optional signal of the x value under the pointer, shared by several charts, e.g. the
+/// stacked charts of a dashboard: hovering any of them sets it, and each draws its
+/// crosshair at this x, the nearest point highlighted. The line of the y value is drawn in
+/// the hovered chart only. A shared cursor draws the crosshair without `crosshair=true`.
+///
+/// ```ignore
+///     let cursor = create_rw_signal(None);
+///     view!{
+///         <LineChart chart=chart_cpu shared_cursor=cursor />
+///         <LineChart chart=chart_memory shared_cursor=cursor />
+///     }
+/// ```
+///
+/// ## About on_brush
+///
+/// Set `on_brush` to select a range by dragging the pointer over the chart, e.g. to zoom a
+/// detailed chart on it: the selection is drawn as a translucent rectangle and, on release,
+/// the callback gets the values of the horizontal axis at its ends, the smaller first
+/// (the x values, Unix timestamps with `x_time`, unless `orientation` is vertical).
+/// A click without dragging clears the selection.
+///
+/// ## About zoomable
+///
+/// Set `zoomable=true` to explore dense data: the mouse wheel zooms the region of the chart
+/// around the pointer, up to 20 times, a drag pans it and a double click resets it.
+/// With `on_brush`, a drag selects a range instead of panning.
+/// The zoom is visual: the axes stay fixed, the lines and the texts of the data are magnified.
+///
+/// ## About annotations
+///
+/// Optional [`Annotation`](crate::Annotation) callouts at values of x and y, e.g.
+/// `Annotation::new(3., 5., "peak")`: a marker at the point with its text just above it,
+/// or at an `offset` in pixels joined by a leader line. The texts are kept in the region
+/// of the chart near its edges.
+///
+/// ## About y_errors
+///
+/// Optional `y_errors`, one error per point, e.g. the standard deviations of measures:
+/// an error bar is drawn from `value - error` to `value + error` at each point, with caps
+/// at its ends, in the `color` of the line. The points missing a value or an error have
+/// no bar. The errors must have as many values as the x series, as the y series.
+///
+/// ## About orientation
+///
+/// - `Orientation::Horizontal` (default): x series on the horizontal axis
+/// - `Orientation::Vertical`: x series on the vertical axis, y series on the horizontal axis
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead,
+/// or `empty_message` for an empty series.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
 #[allow(non_snake_case)]
 #[component]
 pub fn LineChart(
     chart: coord::Cartesian,
     #[prop(default = Color::default())] color: Color,
+    #[prop(default = Orientation::Horizontal)] orientation: Orientation,
+    #[prop(default = 2.0)] stroke_width: f64,
+    #[prop(optional)] color_series: Option<Series>,
+    #[prop(optional)] color_low: Option<Color>,
+    #[prop(default = 2.0)] point_radius: f64,
+    #[prop(default = Color::from("#ff0000"))] point_color: Color,
+    #[prop(default = true)] show_points: bool,
+    #[prop(default = None)] max_points: Option<usize>,
+    #[prop(default = GapMode::Skip)] gaps: GapMode,
+    #[prop(optional)] y_errors: Option<Series>,
+    #[prop(default = false)] point_labels: bool,
+    #[prop(optional)] point_label_format: Option<Callback<f64, String>>,
+    #[prop(default = 1)] label_every: usize,
+    #[prop(default = LineKind::Linear)] kind: LineKind,
+    #[prop(default = 2)] coord_precision: usize,
+    #[prop(default = false)] fill_area: bool,
+    #[prop(default = false)] tooltips: bool,
+    #[prop(default = false)] grid: bool,
+    #[prop(optional, into)] x_title: Option<String>,
+    #[prop(optional, into)] y_title: Option<String>,
+    #[prop(default = Scale::Linear)] y_scale: Scale,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+    #[prop(default = false)] responsive: bool,
+    #[prop(default = false)] fixed_size: bool,
+    #[prop(default = LineStyle::Solid)] style: LineStyle,
+    #[prop(default = LineJoin::Miter)] line_join: LineJoin,
+    #[prop(default = LineCap::Butt)] line_cap: LineCap,
+    #[prop(default = Marker::Circle)] marker: Marker,
+    #[prop(optional)] axis_color: Option<Color>,
+    #[prop(default = false)] animate: bool,
+    #[prop(default = 300.0)] animate_ms: f64,
+    #[prop(optional)] previous_points: Option<StoredValue<RunPoints>>,
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] subtitle: Option<String>,
+    #[prop(default = 1.25 * REM)] title_size: f64,
+    #[prop(default = 0.875 * REM)] subtitle_size: f64,
+    #[prop(optional)] x_tick_format: Option<Callback<f64, String>>,
+    #[prop(default = 0.0)] x_label_rotation: f32,
+    #[prop(default = None)] x_label_max_chars: Option<usize>,
+    #[prop(default = false)] x_label_wrap: bool,
+    #[prop(optional)] y_tick_format: Option<Callback<f64, String>>,
+    #[prop(optional)] h_lines: Vec<(f64, Color)>,
+    #[prop(optional)] bands: Vec<(f64, f64, Color)>,
+    #[prop(optional)] annotations: Vec<Annotation>,
+    #[prop(default = false)] y_begin_zero: bool,
+    #[prop(default = false)] x_time: bool,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional, into)] empty_message: Option<String>,
+    #[prop(optional)] background: Option<Color>,
+    #[prop(optional)] plot_background: Option<Color>,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+    #[prop(default = false)] gradient: bool,
+    #[prop(default = false)] show_zero_line: bool,
+    #[prop(optional)] x_ticks: Option<usize>,
+    #[prop(optional)] y_ticks: Option<usize>,
+    #[prop(default = true)] nice_axis: bool,
+    #[prop(default = false)] crosshair: bool,
+    #[prop(optional)] shared_cursor: Option<RwSignal<Option<f64>>>,
+    #[prop(optional)] on_brush: Option<Callback<(f64, f64)>>,
+    #[prop(default = false)] zoomable: bool,
+    #[prop(default = YAxisSide::Left)] y_axis_side: YAxisSide,
+    #[prop(default = false)] y_reverse: bool,
+    #[prop(default = Dir::Ltr)] dir: Dir,
+    #[prop(default = false)] keyboard_nav: bool,
 ) -> impl IntoView {
     let cview = chart.get_view();
+    let axis_color = theme_axis_color(axis_color, &theme);
+    // For accessibility: a basic description of the data
+    let desc = format!("Line chart of {} points", chart.get_ax().get_count());
+    if let Err(error) = check_series(&chart.get_ax(), &chart.get_ay()) {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            node_ref=node_ref
+            responsive=responsive
+            fixed_size=fixed_size
+            title=title
+            subtitle=subtitle
+            title_size=title_size
+            subtitle_size=subtitle_size
+            aria_label=aria_label.clone()
+            background=background.clone()
+            plot_background=plot_background.clone()
+            theme=theme.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, empty_message)/>
+          </SvgChart>
+        };
+    }
+    let mut error = check_lengths(&chart.get_ax(), &chart.get_ay()).err();
+    if let Some(Err(err)) = y_errors
+        .as_ref()
+        .map(|y_errors| check_lengths(&chart.get_ax(), y_errors))
+    {
+        error = Some(err);
+    }
 
-    // For Chart
+    // For scale of x: numbers, or Unix timestamps with the sticks at dates
+    let (series_x, axes_series_x) = if x_time {
+        time_series(&chart.get_ax()).unwrap_or_else(|err| {
+            error = Some(err);
+            (chart.get_ax(), Axes::default())
+        })
+    } else {
+        nice_series(&chart.get_ax(), x_ticks, nice_axis)
+    };
+
+    // For scale of y
+    let (series_y, axes_series_y) = match y_scale {
+        Scale::Linear => {
+            let series_y = if y_begin_zero {
+                include_zero(&chart.get_ay())
+            } else {
+                chart.get_ay()
+            };
+            nice_series(&series_y, y_ticks, nice_axis)
+        }
+        Scale::Log10 => log10_series(&chart.get_ay()).unwrap_or_else(|err| {
+            error = Some(err);
+            (chart.get_ay(), Axes::default())
+        }),
+    };
+
+    // For orientation: series_h is drawn horizontally, series_v vertically
+    let (series_h, series_v, axes_x, axes_y) = match orientation {
+        Orientation::Horizontal => (series_x, series_y, axes_series_x, axes_series_y),
+        Orientation::Vertical => (series_y, series_x, axes_series_y, axes_series_x),
+    };
+
+    // For side of y-axis: moved from the left to the right edge of the chart,
+    // the chart and the x-axis are shifted left by its width
     let rec_chart = cview.get_rec_chart();
+    let rec_ya = cview.get_rec_y_axis();
+    let (shift, rec_ya) = match y_axis_side {
+        YAxisSide::Right if rec_ya.get_vector().get_x() < 0. => {
+            let shift = rec_ya.get_vector().get_x();
+            let origin = Point::new(
+                rec_chart.get_origin().get_x() + shift + rec_chart.get_vector().get_x(),
+                rec_ya.get_origin().get_y(),
+            );
+            let vector = Vector::new(-shift, rec_ya.get_vector().get_y());
+            (shift, Rec::new(origin, vector))
+        }
+        _ => (0., rec_ya),
+    };
+
+    // For dir: the chart and the axes mirrored in the view, from right to left
+    let rec_xa = cview.get_rec_x_axis();
+    let (shift, rec_chart, rec_xa, rec_ya) = match dir {
+        Dir::Ltr => (shift, rec_chart, rec_xa, rec_ya),
+        Dir::Rtl => {
+            let width = cview.get_vector().get_x() - 2. * cview.get_margin();
+            (
+                -shift,
+                mirror_horizontal(&rec_chart, width),
+                mirror_horizontal(&rec_xa, width),
+                mirror_horizontal(&rec_ya, width),
+            )
+        }
+    };
+
+    // For y_reverse: the chart and the y-axis from their top edge
+    let (rec_chart, rec_ya) = if y_reverse {
+        (reverse_vertical(&rec_chart), reverse_vertical(&rec_ya))
+    } else {
+        (rec_chart, rec_ya)
+    };
+
+    // For Chart
     let translate_chart = format!(
         "translate({},{})",
-        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_x() + shift,
         rec_chart.get_origin().get_y()
     );
 
     // For x-axis
-    let rec_xa = cview.get_rec_x_axis();
     let translate_xa = format!(
         "translate({},{})",
-        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_x() + shift,
         rec_xa.get_origin().get_y()
     );
-    let series_x = chart.get_ax();
-    let axes_x = series_x.gen_axes();
 
     // For y-axis
-    let rec_ya = cview.get_rec_y_axis();
     let translate_ya = format!(
         "translate({},{})",
         rec_ya.get_origin().get_x(),
         rec_ya.get_origin().get_y()
     );
-    let series_y = chart.get_ay();
-    let axes_y = series_y.gen_axes();
 
-    // For chart
-    let xseries = chart.get_ax();
-    let yseries = chart.get_ay();
-    let xsticks = xseries.to_stick();
+    // For tooltip: position and text of the hovered point, the values of the data
+    let x_labels = matches!(chart.get_ax(), Series::Label(_));
+    let labels = chart
+        .get_ax()
+        .to_stick()
+        .into_iter()
+        .zip(chart.get_ay().to_stick())
+        .map(|(x, y)| {
+            let x = if x_time {
+                format_timestamp(x.value, "%Y-%m-%d %H:%M")
+            } else if x_labels {
+                x.label
+            } else {
+                x.value.to_string()
+            };
+            format!("{}, {}", x, y.value)
+        })
+        .collect::<Vec<_>>();
+    let hovered = create_rw_signal::<Option<(f64, f64, String)>>(None);
+
+    // For crosshair: pointer in the coordinates of the chart, over a region catching its moves
+    let crosshair = crosshair || shared_cursor.is_some();
+    let pointer = create_rw_signal::<Option<(f64, f64)>>(None);
+    let region = create_node_ref::<Rect>();
+    let (vector_x, vector_y) = (
+        rec_chart.get_vector().get_x(),
+        rec_chart.get_vector().get_y(),
+    );
+    let position = move |event: &MouseEvent| {
+        let region = region.get_untracked()?;
+        let bounds = region.get_bounding_client_rect();
+        if bounds.width() <= 0. || bounds.height() <= 0. {
+            return None;
+        }
+        let fraction_x = (event.client_x() as f64 - bounds.left()) / bounds.width();
+        let fraction_y = (event.client_y() as f64 - bounds.top()) / bounds.height();
+        Some((
+            vector_x.min(0.) + fraction_x * vector_x.abs(),
+            vector_y.min(0.) + fraction_y * vector_y.abs(),
+        ))
+    };
+
+    // For brush: horizontal range of the selection, from the press to the pointer
+    let brush = create_rw_signal::<Option<(f64, f64)>>(None);
+    let brushing = create_rw_signal(false);
 
-    let ysticks = yseries.to_stick();
+    // For zoom: scale and offset of the data, the zoomed data covering the whole region
+    let zoom = create_rw_signal((1., 0., 0.));
+    let panning = create_rw_signal::<Option<(f64, f64)>>(None);
+    let clamp_zoom = move |scale: f64, x: f64, y: f64| {
+        let scale = scale.clamp(1., MAX_ZOOM);
+        let offset = |value: f64, vector: f64| {
+            value.clamp((1. - scale) * vector.max(0.), (1. - scale) * vector.min(0.))
+        };
+        (scale, offset(x, vector_x), offset(y, vector_y))
+    };
+    let zoom_transform = move || {
+        zoomable.then(|| {
+            let (scale, x, y) = zoom.get();
+            format!("translate({},{}) scale({})", x, y, scale)
+        })
+    };
+    let on_wheel = move |event: WheelEvent| {
+        if !zoomable {
+            return;
+        }
+        if let Some((x, y)) = position(&event) {
+            // Not scrolling the page while zooming
+            event.prevent_default();
+            let (scale, dx, dy) = zoom.get_untracked();
+            let zoomed = (scale * (-event.delta_y() * 0.002).exp()).clamp(1., MAX_ZOOM);
+            // The point under the pointer stays under it
+            zoom.set(clamp_zoom(
+                zoomed,
+                dx + (scale - zoomed) * x,
+                dy + (scale - zoomed) * y,
+            ));
+        }
+    };
+
+    // For shared cursor: the x values along the axis of the x series, horizontal or vertical
+    let (cursor_series, cursor_length) = match orientation {
+        Orientation::Horizontal => (series_h.clone(), vector_x),
+        Orientation::Vertical => (series_v.clone(), vector_y),
+    };
+    let cursor_value = {
+        let cursor_series = cursor_series.clone();
+        move |(x, y): (f64, f64)| {
+            let along = match orientation {
+                Orientation::Horizontal => x,
+                Orientation::Vertical => y,
+            };
+            cursor_series.unscale(along / cursor_length)
+        }
+    };
 
-    if chart.get_error() == String::default() {
+    let on_move = move |event: MouseEvent| {
+        let position = position(&event);
+        pointer.set(position.filter(|_| crosshair));
+        if let Some(shared_cursor) = shared_cursor {
+            shared_cursor.set(position.map(&cursor_value));
+        }
+        if let (true, Some((x, _))) = (brushing.get_untracked(), position) {
+            brush.update(|brush| {
+                if let Some((_, to)) = brush {
+                    *to = x;
+                }
+            });
+        }
+        if let (Some((from_x, from_y)), Some((x, y))) = (panning.get_untracked(), position) {
+            // The point pressed stays under the pointer
+            let (scale, dx, dy) = zoom.get_untracked();
+            zoom.set(clamp_zoom(
+                scale,
+                dx + scale * (x - from_x),
+                dy + scale * (y - from_y),
+            ));
+        }
+    };
+    let on_press = move |event: MouseEvent| {
+        if on_brush.is_none() && !zoomable {
+            return;
+        }
+        if let Some((x, y)) = position(&event) {
+            // Not selecting the texts of the page while dragging
+            event.prevent_default();
+            if on_brush.is_some() {
+                brush.set(Some((x, x)));
+                brushing.set(true);
+            } else {
+                panning.set(Some((x, y)));
+            }
+        }
+    };
+    // Fired on release, a selection narrower than 2 pixels is a click clearing it
+    let series_brush = series_h.clone();
+    let end_brush = Callback::new(move |_: ()| {
+        panning.set(None);
+        if !brushing.get_untracked() {
+            return;
+        }
+        brushing.set(false);
+        match (brush.get_untracked(), on_brush) {
+            (Some((from, to)), Some(on_brush)) if (to - from).abs() > 2. => {
+                let value = |x: f64| series_brush.unscale(x / vector_x);
+                let (from, to) = (value(from), value(to));
+                on_brush.call((from.min(to), from.max(to)));
+            }
+            _ => brush.set(None),
+        }
+    });
+    let crosshair_points = build_line_points(&series_h, &series_v, &rec_chart.get_vector());
+
+    // For labels of the points: the y values of every n-th point
+    let point_texts = if point_labels {
+        crosshair_points
+            .iter()
+            .zip(chart.get_ay().to_stick())
+            .step_by(label_every.max(1))
+            .map(|(&(x, y), stick)| {
+                let text = point_label_format
+                    .map_or(stick.value.to_string(), |format| format.call(stick.value));
+                (x, y, text)
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+
+    // For y_errors: the bars from value - error to value + error along the axis of y,
+    // at the points with both values
+    let (series_errors, length) = match orientation {
+        Orientation::Horizontal => (&series_v, vector_y),
+        Orientation::Vertical => (&series_h, vector_x),
+    };
+    let end = |value: f64| {
+        let value = match y_scale {
+            Scale::Linear => value,
+            Scale::Log10 => value.max(f64::MIN_POSITIVE).log10(),
+        };
+        // Within the region, e.g. the lower end of non-positive values on a log scale
+        series_errors.scale(value).clamp(0., 1.) * length
+    };
+    let error_bars = y_errors
+        .map(|y_errors| {
+            chart
+                .get_ay()
+                .to_stick()
+                .into_iter()
+                .zip(y_errors.to_stick())
+                .zip(&crosshair_points)
+                .filter(|((value, error), _)| value.value.is_finite() && error.value.is_finite())
+                .map(|((value, error), &(x, y))| {
+                    let (low, high) = (
+                        end(value.value - error.value.abs()),
+                        end(value.value + error.value.abs()),
+                    );
+                    match orientation {
+                        Orientation::Horizontal => ((x, low), (x, high)),
+                        Orientation::Vertical => ((low, y), (high, y)),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    // For annotations: the points, and their texts kept in the region near its edges
+    let (left, right) = (vector_x.min(0.), vector_x.max(0.));
+    let (top, bottom) = (vector_y.min(0.), vector_y.max(0.));
+    let annotations = annotations
+        .into_iter()
+        .filter(|annotation| annotation.x.is_finite() && annotation.y.is_finite())
+        .map(|annotation| {
+            let value = match y_scale {
+                Scale::Linear => annotation.y,
+                Scale::Log10 => annotation.y.log10(),
+            };
+            let (h, v) = match orientation {
+                Orientation::Horizontal => (annotation.x, value),
+                Orientation::Vertical => (value, annotation.x),
+            };
+            let (x, y) = (series_h.scale(h) * vector_x, series_v.scale(v) * vector_y);
+            let (dx, dy) = annotation.offset.unwrap_or((0., -REM / 2.));
+            let half = annotation.text.chars().count() as f64 * 0.3 * REM;
+            let text_x = (x + dx).clamp(left + half, (right - half).max(left + half));
+            let text_y = (y + dy).clamp(top + REM, bottom.max(top + REM));
+            // The leader line ends below the text above the point, else above the text
+            let leader_y = if text_y < y {
+                text_y + REM / 4.
+            } else {
+                text_y - REM
+            };
+            (x, y, text_x, text_y, leader_y, annotation)
+        })
+        .collect::<Vec<_>>();
+
+    // For keyboard navigation: index of the focused point, moved by the arrow keys
+    let focused = create_rw_signal::<Option<usize>>(None);
+    let focus_points = crosshair_points.clone();
+    let focus_labels = labels.clone();
+    let count = focus_points.len();
+    let on_keydown = (keyboard_nav && count > 0).then(|| {
+        Callback::new(move |event: KeyboardEvent| {
+            // From right to left, the next point is on the left
+            let key = match (dir, event.key().as_str()) {
+                (Dir::Rtl, "ArrowRight") => "ArrowLeft",
+                (Dir::Rtl, "ArrowLeft") => "ArrowRight",
+                (_, key) => key,
+            }
+            .to_string();
+            let index = match (key.as_str(), focused.get_untracked()) {
+                ("ArrowRight", Some(index)) => (index + 1).min(count - 1),
+                ("ArrowRight", None) => 0,
+                ("ArrowLeft", Some(index)) => index.saturating_sub(1),
+                ("ArrowLeft", None) => count - 1,
+                _ => return,
+            };
+            event.prevent_default();
+            focused.set(Some(index));
+        })
+    });
+
+    if let Some(error) = error {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        view! {
+          <SvgChart
+            cview=cview
+            node_ref=node_ref
+            responsive=responsive
+            fixed_size=fixed_size
+            title=title
+            subtitle=subtitle
+            title_size=title_size
+            subtitle_size=subtitle_size
+            aria_label=aria_label.clone()
+            background=background.clone()
+            plot_background=plot_background.clone()
+            theme=theme.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        }
+    } else {
         view! {
-          <SvgChart cview=cview>
-            <g class="axes">
-              <g class="x-axis" transform=translate_xa>
-                <XAxis region=rec_xa axes=axes_x/>
+          <SvgChart
+            cview=cview
+            node_ref=node_ref
+            responsive=responsive
+            fixed_size=fixed_size
+            title=title
+            subtitle=subtitle
+            title_size=title_size
+            subtitle_size=subtitle_size
+            aria_label=aria_label.clone()
+            background=background.clone()
+            plot_background=plot_background.clone()
+            theme=theme.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            clip=clip
+            region=Some(rec_chart.clone())
+            dir=dir
+            on_keydown=on_keydown
+          >
+            <g class=class_name("axes")>
+              <g class=class_name("x-axis") transform=translate_xa>
+                <XAxis
+                  region=rec_xa
+                  axes=axes_x.clone()
+                  color=axis_color.clone()
+                  format=x_tick_format
+                  rotation=x_label_rotation
+                  max_label_chars=x_label_max_chars
+                  wrap_labels=x_label_wrap
+                  title=x_title
+                  dir=dir
+                  tick_size=tick_size
+                  tick_dir=tick_dir
+                />
               </g>
-              <g class="y-axis" transform=translate_ya>
-                <YAxis region=rec_ya axes=axes_y/>
+              <g class=class_name("y-axis") transform=translate_ya>
+                <YAxis
+                  region=rec_ya
+                  axes=axes_y.clone()
+                  color=axis_color
+                  format=y_tick_format
+                  title=y_title
+                  dir=dir
+                  tick_size=tick_size
+                  tick_dir=tick_dir
+                />
               </g>
             </g>
-            <g class="inner-chart" transform=translate_chart>
-              // For draw region of chart
-
-              {#[cfg(feature = "debug")]
-              {
-                  let vector = rec_chart.get_vector();
-                  let path = format!(
-                      "M {},{} l {},{} l {},{} l {},{} Z",
-                      0,
-                      0,
-                      vector.get_x(),
-                      0,
-                      0,
-                      vector.get_y(),
-                      -vector.get_x(),
-                      0,
-                  );
-                  view! {
-                    <circle id="originY" cx="0" cy="0" r="3"></circle>
-                    <line
-                      x1="0"
-                      y1="0"
-                      x2=vector.get_x()
-                      y2=vector.get_y()
-                      style="stroke:#00ff0033;stroke-width:2"
-                    ></line>
-                    <path id="regionY" d=path fill="#00ff0033"></path>
+            <g
+              class=class_name("inner-chart")
+              transform=translate_chart.clone()
+              clip-path=clip_path()
+              on:mousemove=on_move
+              on:mousedown=on_press
+              on:wheel=on_wheel
+              on:dblclick=move |_| zoom.set((1., 0., 0.))
+              on:mouseup=move |_| end_brush.call(())
+              on:mouseleave=move |_| {
+                  pointer.set(None);
+                  if let Some(shared_cursor) = shared_cursor {
+                      shared_cursor.set(None);
                   }
-              }}
+                  end_brush.call(());
+              }
+            >
+              <g class=class_name("zoom") transform=zoom_transform>
+                // For draw region of chart
 
-              {
-                  let vector = rec_chart.get_vector();
-                  let mut line = "M".to_string();
-                  let point = xsticks
-                      .clone()
+                <DebugRegion vector=rec_chart.get_vector()/>
+
+                // For grid lines, drawn before the data to be behind it
+                {grid
+                    .then(|| {
+                        view! {
+                          <Grid
+                            region=rec_chart.clone()
+                            axes_x=axes_x
+                            axes_y=axes_y
+                            color=theme_grid_color(&theme)
+                          />
+                        }
+                    })}
+
+                // For crosshair, brush and zoom: the region catching the pointer, behind the data
+                {(crosshair || on_brush.is_some() || zoomable)
+                    .then(|| {
+                        view! {
+                          <rect
+                            node_ref=region
+                            x=vector_x.min(0.)
+                            y=vector_y.min(0.)
+                            width=vector_x.abs()
+                            height=vector_y.abs()
+                            fill="none"
+                            pointer-events="all"
+                          ></rect>
+                        }
+                    })}
+
+                // For zero lines, where zero is in the range of a linear numeric axis
+                {show_zero_line
+                    .then(|| {
+                        let vector = rec_chart.get_vector();
+                        let zero = |series: &Series, linear: bool| match series {
+                            Series::Number(_) if linear => {
+                                Some(series.scale(0.)).filter(|zero| (0. ..=1.).contains(zero))
+                            }
+                            _ => None,
+                        };
+                        let (linear_h, linear_v) = match orientation {
+                            Orientation::Horizontal => (!x_time, y_scale == Scale::Linear),
+                            Orientation::Vertical => (y_scale == Scale::Linear, !x_time),
+                        };
+                        let horizontal = zero(&series_v, linear_v)
+                            .map(|zero| {
+                                let y = zero * vector.get_y();
+                                view! { <line x1=0 y1=y x2=vector.get_x() y2=y></line> }
+                            });
+                        let vertical = zero(&series_h, linear_h)
+                            .map(|zero| {
+                                let x = zero * vector.get_x();
+                                view! { <line x1=x y1=0 x2=x y2=vector.get_y()></line> }
+                            });
+                        view! {
+                          <g
+                            class=class_name("zero-line")
+                            stroke="currentColor"
+                            stroke-opacity="0.5"
+                            stroke-width="1"
+                          >
+                            {horizontal}
+                            {vertical}
+                          </g>
+                        }
+                    })}
+
+                // For bands between values of y, drawn first to be behind the reference lines
+                {
+                    let vector = rec_chart.get_vector();
+                    let scale = move |value: f64| match y_scale {
+                        Scale::Linear => value,
+                        Scale::Log10 => value.log10(),
+                    };
+                    bands
+                        .into_iter()
+                        .filter(|(from, to, _)| scale(*from).is_finite() && scale(*to).is_finite())
+                        .map(|(from, to, color)| {
+                            let (x, y, width, height) = match orientation {
+                                Orientation::Horizontal => {
+                                    let y1 = series_v.scale(scale(from)) * vector.get_y();
+                                    let y2 = series_v.scale(scale(to)) * vector.get_y();
+                                    let x = vector.get_x().min(0.);
+                                    (x, y1.min(y2), vector.get_x().abs(), (y2 - y1).abs())
+                                }
+                                Orientation::Vertical => {
+                                    let x1 = series_h.scale(scale(from)) * vector.get_x();
+                                    let x2 = series_h.scale(scale(to)) * vector.get_x();
+                                    let y = vector.get_y().min(0.);
+                                    (x1.min(x2), y, (x2 - x1).abs(), vector.get_y().abs())
+                                }
+                            };
+                            view! {
+                              <rect
+                                class=class_name("band")
+                                x=x
+                                y=y
+                                width=width
+                                height=height
+                                fill=color.to_string_hex()
+                                fill-opacity="0.2"
+                              ></rect>
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                }
+
+                // For reference lines at values of y, drawn before the data to be behind it;
+                // the values a log axis can not show (zero or negative) are left out
+                {
+                    let vector = rec_chart.get_vector();
+                    let scale = move |value: f64| match y_scale {
+                        Scale::Linear => value,
+                        Scale::Log10 => value.log10(),
+                    };
+                    h_lines
+                        .into_iter()
+                        .filter(|(value, _)| scale(*value).is_finite())
+                        .map(|(value, color)| {
+                            let scaled = scale(value);
+                            let (x1, y1, x2, y2) = match orientation {
+                                Orientation::Horizontal => {
+                                    let y = series_v.scale(scaled) * vector.get_y();
+                                    (0., y, vector.get_x(), y)
+                                }
+                                Orientation::Vertical => {
+                                    let x = series_h.scale(scaled) * vector.get_x();
+                                    (x, 0., x, vector.get_y())
+                                }
+                            };
+                            view! {
+                              <g class=class_name("reference-line") stroke=color.to_string_hex()>
+                                <line x1=x1 y1=y1 x2=x2 y2=y2 stroke-width="1"></line>
+                                <text
+                                  x=x2
+                                  y=y2
+                                  fill=color.to_string_hex()
+                                  stroke="none"
+                                  text-anchor="end"
+                                  dominant-baseline="text-after-edge"
+                                >
+                                  {value}
+                                </text>
+                              </g>
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                }
+
+                // For error bars, behind the line and its markers
+                <g
+                  class=class_name("error-bars")
+                  stroke=color.to_string_hex()
+                  stroke-width="1"
+                  pointer-events="none"
+                >
+                  {error_bars
                       .into_iter()
-                      .enumerate()
-                      .map(|(index, data)| {
-                          let x: f64 = xseries.scale(data.value) * vector.get_x();
-                          let y: f64 = yseries.scale(ysticks[index].value) * vector.get_y();
-                          line.push_str(format!(" {:.0},{:.0} ", x, y).as_str());
+                      .map(|((x1, y1), (x2, y2))| {
+                          // The caps are across the bar
+                          let (cap_x, cap_y) = match orientation {
+                              Orientation::Horizontal => (REM / 4., 0.),
+                              Orientation::Vertical => (0., REM / 4.),
+                          };
                           view! {
-                            <circle
-                              cx=x
-                              cy=y
-                              r="2"
-                              stroke="black"
-                              stroke-width="1"
-                              fill="red"
-                            ></circle>
+                            <line x1=x1 y1=y1 x2=x2 y2=y2></line>
+                            <line x1=x1 - cap_x y1=y1 - cap_y x2=x1 + cap_x y2=y1 + cap_y></line>
+                            <line x1=x2 - cap_x y1=y2 - cap_y x2=x2 + cap_x y2=y2 + cap_y></line>
                           }
                       })
-                      .collect::<Vec<_>>();
-                  view! {
-                    {point}
-                    <path d=line stroke=color.to_string_hex() fill="none"></path>
-                  }
-              }
+                      .collect::<Vec<_>>()}
+                </g>
+
+                {
+                    let vector = rec_chart.get_vector();
+                    // Baseline at the scaled zero, so negative values fill correctly
+                    let baseline = match (y_scale, orientation) {
+                        (Scale::Log10, _) => 0.,
+                        (_, Orientation::Horizontal) => series_v.scale(0.) * vector.get_y(),
+                        (_, Orientation::Vertical) => series_h.scale(0.) * vector.get_x(),
+                    };
+                    // For gaps: the missing values on the baseline with GapMode::Zero
+                    let zero = match orientation {
+                        Orientation::Horizontal => (series_h.scale(0.) * vector.get_x(), baseline),
+                        Orientation::Vertical => (baseline, series_v.scale(0.) * vector.get_y()),
+                    };
+                    let points =
+                        fill_gaps(build_line_points(&series_h, &series_v, &vector), gaps, zero);
+                    // For gaps and max_points: the runs of points drawn as separate segments,
+                    // each downsampled in proportion to its length
+                    let total = points.len().max(1);
+                    let runs = gap_runs(&points, gaps)
+                        .into_iter()
+                        .map(|run| {
+                            let max_points =
+                                max_points.map(|max| (max * run.len()).div_ceil(total));
+                            let run_points =
+                                run.iter().map(|&index| points[index]).collect::<Vec<_>>();
+                            decimate(&run_points, max_points)
+                                .into_iter()
+                                .map(|kept| run[kept])
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>();
+                    let run_points = runs
+                        .iter()
+                        .map(|run| run.iter().map(|&index| points[index]).collect::<Vec<_>>())
+                        .collect::<Vec<_>>();
+                    // For animation: the line moves from the previous points when it has as
+                    // many runs of as many points, else it grows from the baseline
+                    let dur = format!("{}ms", animate_ms);
+                    let previous = previous_points
+                        .map(|previous| previous.get_value())
+                        .filter(|previous| {
+                            previous.len() == run_points.len()
+                                && previous
+                                    .iter()
+                                    .zip(&run_points)
+                                    .all(|(from, to)| from.len() == to.len())
+                        });
+                    if let Some(previous_points) = previous_points {
+                        previous_points.set_value(run_points.clone());
+                    }
+                    let moved = previous.is_some();
+                    let from_points = previous.unwrap_or_else(|| {
+                        run_points
+                            .iter()
+                            .map(|run| {
+                                run.iter()
+                                    .map(|&(x, y)| match orientation {
+                                        Orientation::Horizontal => (x, baseline),
+                                        Orientation::Vertical => (baseline, y),
+                                    })
+                                    .collect::<Vec<_>>()
+                            })
+                            .collect::<Vec<_>>()
+                    });
+                    // Paths of the line and of the area under it, a segment per run
+                    let paths = |runs_points: &[Vec<(f64, f64)>]| {
+                        let (lines, areas): (Vec<_>, Vec<_>) = runs_points
+                            .iter()
+                            .map(|run_points| {
+                                let line = gen_line_path(run_points, kind, coord_precision);
+                                let area = gen_area_path(
+                                    &line,
+                                    run_points,
+                                    baseline,
+                                    orientation,
+                                    coord_precision,
+                                );
+                                (line, area)
+                            })
+                            .unzip();
+                        (lines.join(" "), areas.join(" "))
+                    };
+                    let (line, path) = paths(&run_points);
+                    let (from_line, from_path) = paths(&from_points);
+                    let area = if fill_area {
+                        // For gradient: from the baseline to the end of the values
+                        let id = gradient_id();
+                        let defs = gradient
+                            .then(|| {
+                                let (from, to) = match orientation {
+                                    Orientation::Horizontal => {
+                                        ((0., baseline), (0., vector.get_y()))
+                                    }
+                                    Orientation::Vertical => ((baseline, 0.), (vector.get_x(), 0.)),
+                                };
+                                view! {
+                                  <defs>{linear_gradient(id.clone(), &color, from, to)}</defs>
+                                }
+                            });
+                        let fill = if gradient {
+                            format!("url(#{})", id)
+                        } else {
+                            color.to_string_hex()
+                        };
+                        Some(
+                            view! {
+                              {defs}
+                              <path
+                                class=class_name("area")
+                                d=path.clone()
+                                fill=fill
+                                fill-opacity="0.3"
+                                stroke="none"
+                              >
+                                {animate
+                                    .then(|| {
+                                        view! {
+                                          <animate
+                                            attributeName="d"
+                                            from=from_path
+                                            to=path
+                                            dur=dur.clone()
+                                            fill="freeze"
+                                          ></animate>
+                                        }
+                                    })}
+                              </path>
+                            },
+                        )
+                    } else {
+                        None
+                    };
+                    // For color_series: a path per segment, colored by its average value
+                    let color_values = color_series.as_ref().map(|series| {
+                        series
+                            .to_stick()
+                            .into_iter()
+                            .map(|stick| stick.value)
+                            .collect::<Vec<_>>()
+                    });
+                    let segments = color_values.map(|values| {
+                        let finite = values.iter().copied().filter(|value| value.is_finite());
+                        let min = finite.clone().fold(f64::INFINITY, f64::min);
+                        let max = finite.fold(f64::NEG_INFINITY, f64::max);
+                        let color_low = color_low.clone().unwrap_or_else(|| lighten(&color, 0.8));
+                        runs.iter()
+                            .zip(run_points.iter().zip(&from_points))
+                            .flat_map(|(run, (to, from))| {
+                                (1..run.len()).map(move |end| (&run[end - 1..=end], to, from, end))
+                            })
+                            .map(|(pair, to, from, end)| {
+                                let value = match (values.get(pair[0]), values.get(pair[1])) {
+                                    (Some(from), Some(to)) => (from + to) / 2.,
+                                    _ => f64::NAN,
+                                };
+                                let segment_color = if !value.is_finite() {
+                                    color.clone()
+                                } else if max > min {
+                                    mix(&color_low, &color, (value - min) / (max - min))
+                                } else {
+                                    color.clone()
+                                };
+                                (
+                                    gen_line_path(&to[end - 1..=end], kind, coord_precision),
+                                    gen_line_path(&from[end - 1..=end], kind, coord_precision),
+                                    segment_color,
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                    });
+                    let markers = if show_points {
+                        runs
+                            .iter()
+                            .flatten()
+                            .zip(from_points.iter().flatten())
+                            .map(|(&index, &(from_x, from_y))| {
+                                let (x, y) = points[index];
+                                let tooltip = tooltips.then(|| (x, y, labels[index].clone()));
+                                let fill = point_color.to_string_hex();
+                                // For animation: the marker moves from its previous position
+                                let motion = (animate && moved).then(|| {
+                                    view! {
+                                      <animateTransform
+                                        attributeName="transform"
+                                        type="translate"
+                                        from=format!("{} {}", from_x - x, from_y - y)
+                                        to="0 0"
+                                        dur=dur.clone()
+                                        fill="freeze"
+                                      ></animateTransform>
+                                    }
+                                });
+                                match marker {
+                                    Marker::Circle => {
+                                        let circle = view! {
+                                          <circle
+                                            cx=x
+                                            cy=y
+                                            r=point_radius
+                                            stroke="black"
+                                            stroke-width="1"
+                                            fill=fill
+                                          >
+                                            {motion}
+                                          </circle>
+                                        };
+                                        with_tooltip(circle, hovered, tooltip).into_view()
+                                    }
+                                    Marker::Square => {
+                                        let square = view! {
+                                          <rect
+                                            x=x - point_radius
+                                            y=y - point_radius
+                                            width=2. * point_radius
+                                            height=2. * point_radius
+                                            stroke="black"
+                                            stroke-width="1"
+                                            fill=fill
+                                          >
+                                            {motion}
+                                          </rect>
+                                        };
+                                        with_tooltip(square, hovered, tooltip).into_view()
+                                    }
+                                    Marker::Triangle | Marker::Diamond => {
+                                        let polygon = view! {
+                                          <polygon
+                                            points=gen_marker_points(marker, x, y, point_radius)
+                                            stroke="black"
+                                            stroke-width="1"
+                                            fill=fill
+                                          >
+                                            {motion}
+                                          </polygon>
+                                        };
+                                        with_tooltip(polygon, hovered, tooltip).into_view()
+                                    }
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    } else {
+                        vec![]
+                    };
+                    view! {
+                      {area}
+                      <g class=class_name("markers")>
+                        {markers}
+                        {(animate && !moved)
+                            .then(|| {
+                                view! {
+                                  <animate
+                                    attributeName="opacity"
+                                    from="0"
+                                    to="1"
+                                    dur=dur.clone()
+                                    fill="freeze"
+                                  ></animate>
+                                }
+                            })}
+                      </g>
+                      {match segments {
+                          Some(segments) => {
+                              view! {
+                                <g
+                                  class=class_name("line")
+                                  stroke-width=stroke_width
+                                  stroke-dasharray=style.dasharray()
+                                  stroke-linejoin=line_join.attribute()
+                                  stroke-linecap=line_cap.attribute()
+                                  fill="none"
+                                >
+                                  {segments
+                                      .into_iter()
+                                      .map(|(segment, from_segment, segment_color)| {
+                                          view! {
+                                            <path
+                                              class=class_name("segment")
+                                              d=segment.clone()
+                                              stroke=segment_color.to_string_hex()
+                                            >
+                                              {animate
+                                                  .then(|| {
+                                                      view! {
+                                                        <animate
+                                                          attributeName="d"
+                                                          from=from_segment
+                                                          to=segment
+                                                          dur=dur.clone()
+                                                          fill="freeze"
+                                                        ></animate>
+                                                      }
+                                                  })}
+                                            </path>
+                                          }
+                                      })
+                                      .collect::<Vec<_>>()}
+                                </g>
+                              }
+                                  .into_view()
+                          }
+                          None => {
+                              view! {
+                                <path
+                                  class=class_name("line")
+                                  d=line.clone()
+                                  stroke=color.to_string_hex()
+                                  stroke-width=stroke_width
+                                  stroke-dasharray=style.dasharray()
+                                  stroke-linejoin=line_join.attribute()
+                                  stroke-linecap=line_cap.attribute()
+                                  fill="none"
+                                >
+                                  {animate
+                                      .then(|| {
+                                          view! {
+                                            <animate
+                                              attributeName="d"
+                                              from=from_line
+                                              to=line
+                                              dur=dur
+                                              fill="freeze"
+                                            ></animate>
+                                          }
+                                      })}
+                                </path>
+                              }
+                                  .into_view()
+                          }
+                      }}
+                    }
+                }
 
+                // For annotations, over the data
+                <g class=class_name("annotations") pointer-events="none">
+                  {annotations
+                      .into_iter()
+                      .map(|(x, y, text_x, text_y, leader_y, annotation)| {
+                          let color = annotation
+                              .color
+                              .map_or("currentColor".to_string(), |color| color.to_string_hex());
+                          view! {
+                            <g class=class_name("annotation")>
+                              {annotation
+                                  .offset
+                                  .map(|_| {
+                                      view! {
+                                        <line
+                                          x1=x
+                                          y1=y
+                                          x2=text_x
+                                          y2=leader_y
+                                          stroke=color.clone()
+                                          stroke-width="1"
+                                        ></line>
+                                      }
+                                  })}
+                              <circle cx=x cy=y r="3" fill=color.clone()></circle>
+                              <text x=text_x y=text_y text-anchor="middle" fill="currentColor">
+                                {annotation.text}
+                              </text>
+                            </g>
+                          }
+                      })
+                      .collect::<Vec<_>>()}
+                </g>
+
+                // For brush: the selection, over the data
+                {move || {
+                    brush
+                        .get()
+                        .map(|(from, to)| {
+                            view! {
+                              <rect
+                                class=class_name("brush")
+                                x=from.min(to)
+                                y=vector_y.min(0.)
+                                width=(to - from).abs()
+                                height=vector_y.abs()
+                                fill="currentColor"
+                                fill-opacity="0.15"
+                                pointer-events="none"
+                              ></rect>
+                            }
+                        })
+                }}
+
+                // For crosshair: lines at the pointer, or at the shared x and at the pointer,
+                // the nearest point highlighted
+                {move || {
+                    let local = pointer.get();
+                    let (x, y) = match shared_cursor {
+                        Some(shared_cursor) => {
+                            let along = shared_cursor
+                                .get()
+                                .map(|value| cursor_series.scale(value) * cursor_length);
+                            match orientation {
+                                Orientation::Horizontal => (along, local.map(|(_, y)| y)),
+                                Orientation::Vertical => (local.map(|(x, _)| x), along),
+                            }
+                        }
+                        None => (local.map(|(x, _)| x), local.map(|(_, y)| y)),
+                    };
+                    let along = match orientation {
+                        Orientation::Horizontal => x,
+                        Orientation::Vertical => y,
+                    };
+                    along
+                        .map(|along| {
+                            let nearest = crosshair_points
+                                .iter()
+                                .min_by(|a, b| {
+                                    let distance = |&(px, py): &(f64, f64)| match orientation {
+                                        Orientation::Horizontal => (px - along).abs(),
+                                        Orientation::Vertical => (py - along).abs(),
+                                    };
+                                    distance(a).total_cmp(&distance(b))
+                                })
+                                .copied();
+                            view! {
+                              <g
+                                class=class_name("crosshair")
+                                stroke="currentColor"
+                                stroke-opacity="0.6"
+                                stroke-dasharray="4,4"
+                                pointer-events="none"
+                              >
+                                {x.map(|x| view! { <line x1=x y1=0 x2=x y2=vector_y></line> })}
+                                {y.map(|y| view! { <line x1=0 y1=y x2=vector_x y2=y></line> })}
+                                {nearest
+                                    .map(|(px, py)| {
+                                        view! {
+                                          <circle
+                                            cx=px
+                                            cy=py
+                                            r=2. * point_radius
+                                            fill=point_color.to_string_hex()
+                                            stroke-dasharray="none"
+                                          ></circle>
+                                        }
+                                    })}
+                              </g>
+                            }
+                        })
+                }}
+
+                // For keyboard navigation: the focused point, its values announced
+                {keyboard_nav
+                    .then(|| {
+                        let point = move || {
+                            focused.get().and_then(|index| focus_points.get(index).copied())
+                        };
+                        let text = move || {
+                            focused.get().and_then(|index| focus_labels.get(index).cloned())
+                        };
+                        view! {
+                          {move || {
+                              point()
+                                  .map(|(x, y)| {
+                                      view! {
+                                        <circle
+                                          class=class_name("focused")
+                                          cx=x
+                                          cy=y
+                                          r=2. * point_radius
+                                          fill="none"
+                                          stroke="currentColor"
+                                          stroke-width=2
+                                          pointer-events="none"
+                                        ></circle>
+                                      }
+                                  })
+                          }}
+                          <text class=class_name("announce") aria-live="polite" opacity=0>
+                            {text}
+                          </text>
+                        }
+                    })}
+
+                // For tooltip, drawn last to be above the chart
+                {move || {
+                    hovered
+                        .get()
+                        .map(|(x, y, text)| {
+                            let width = text.len() as f64 * 0.6 * REM + REM;
+                            view! {
+                              <g
+                                class=class_name("tooltip")
+                                transform=format!("translate({},{})", x + REM / 2., y - 2. * REM)
+                                pointer-events="none"
+                              >
+                                <rect
+                                  width=width
+                                  height=1.5 * REM
+                                  rx="3"
+                                  fill="#ffffffdd"
+                                  stroke="currentColor"
+                                ></rect>
+                                <text x=REM / 2. y=0.75 * REM dominant-baseline="middle">
+                                  {text}
+                                </text>
+                              </g>
+                            }
+                        })
+                }}
+
+              </g>
+            </g>
+
+            // For labels of the points, out of the clip of the chart
+            <g
+              class=class_name("point-labels")
+              transform=move || {
+                  format!("{} {}", translate_chart, zoom_transform().unwrap_or_default())
+              }
+              clip-path=zoomable.then(clip_path).flatten()
+              text-anchor="middle"
+              dominant-baseline="text-after-edge"
+              pointer-events="none"
+            >
+              {point_texts
+                  .into_iter()
+                  .map(|(x, y, text)| {
+                      view! {
+                        <text x=x y=y - point_radius - REM / 4. fill="currentColor">
+                          {text}
+                        </text>
+                      }
+                  })
+                  .collect::<Vec<_>>()}
             </g>
           </SvgChart>
         }
-    } else {
-        let err = chart.get_error();
-        log::error!("{}", err);
-        view! {
-          <SvgChart cview=cview>
-            <g></g>
-          </SvgChart>
-        }
+    }
+}
+
+// Marker with the handlers showing its tooltip on hover, unchanged without tooltip
+fn with_tooltip<El: ElementDescriptor + 'static>(
+    marker: HtmlElement<El>,
+    hovered: RwSignal<Option<(f64, f64, String)>>,
+    tooltip: Option<(f64, f64, String)>,
+) -> HtmlElement<El> {
+    match tooltip {
+        Some(tooltip) => marker
+            .on(mouseenter, move |_| hovered.set(Some(tooltip.clone())))
+            .on(mouseleave, move |_| hovered.set(None)),
+        None => marker,
+    }
+}
+
+/// Component LineChartReactive for leptos
+///
+/// Same as [`LineChart`], but the chart is given as a signal: when the signal
+/// is updated, the chart (axes included) is rendered again.
+///
+/// # Examples
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let chart = Cartesian::new(
+///         Series::from(vec![1.0, 6.0, 9.]),
+///         Series::from(vec![1.0, 3.0, 5.])
+///     )
+///     .set_view(820, 620, 3, 100, 100, 20);
+///     let (chart, set_chart) = create_signal(chart);
+///
+///     // later, e.g. on new sensor data: set_chart.set(new_chart)
+///     view!{
+///         <LineChartReactive chart=chart />
+///     }
+/// }
+/// ```
+///
+/// The other props are the same as for [`LineChart`].
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn LineChartReactive(
+    #[prop(into)] chart: Signal<coord::Cartesian>,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(default = Orientation::Horizontal)] orientation: Orientation,
+    #[prop(default = 2.0)] stroke_width: f64,
+    #[prop(optional)] color_series: Option<Series>,
+    #[prop(optional)] color_low: Option<Color>,
+    #[prop(default = 2.0)] point_radius: f64,
+    #[prop(default = Color::from("#ff0000"))] point_color: Color,
+    #[prop(default = true)] show_points: bool,
+    #[prop(default = None)] max_points: Option<usize>,
+    #[prop(default = GapMode::Skip)] gaps: GapMode,
+    #[prop(optional)] y_errors: Option<Series>,
+    #[prop(default = false)] point_labels: bool,
+    #[prop(optional)] point_label_format: Option<Callback<f64, String>>,
+    #[prop(default = 1)] label_every: usize,
+    #[prop(default = LineKind::Linear)] kind: LineKind,
+    #[prop(default = 2)] coord_precision: usize,
+    #[prop(default = false)] fill_area: bool,
+    #[prop(default = false)] tooltips: bool,
+    #[prop(default = false)] grid: bool,
+    #[prop(optional, into)] x_title: Option<String>,
+    #[prop(optional, into)] y_title: Option<String>,
+    #[prop(default = Scale::Linear)] y_scale: Scale,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+    #[prop(default = false)] responsive: bool,
+    #[prop(default = false)] fixed_size: bool,
+    #[prop(default = LineStyle::Solid)] style: LineStyle,
+    #[prop(default = LineJoin::Miter)] line_join: LineJoin,
+    #[prop(default = LineCap::Butt)] line_cap: LineCap,
+    #[prop(default = Marker::Circle)] marker: Marker,
+    #[prop(optional)] axis_color: Option<Color>,
+    #[prop(default = false)] animate: bool,
+    #[prop(default = 300.0)] animate_ms: f64,
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] subtitle: Option<String>,
+    #[prop(default = 1.25 * REM)] title_size: f64,
+    #[prop(default = 0.875 * REM)] subtitle_size: f64,
+    #[prop(optional)] x_tick_format: Option<Callback<f64, String>>,
+    #[prop(default = 0.0)] x_label_rotation: f32,
+    #[prop(default = None)] x_label_max_chars: Option<usize>,
+    #[prop(default = false)] x_label_wrap: bool,
+    #[prop(optional)] y_tick_format: Option<Callback<f64, String>>,
+    #[prop(optional)] h_lines: Vec<(f64, Color)>,
+    #[prop(optional)] bands: Vec<(f64, f64, Color)>,
+    #[prop(optional)] annotations: Vec<Annotation>,
+    #[prop(default = false)] y_begin_zero: bool,
+    #[prop(default = false)] x_time: bool,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional, into)] empty_message: Option<String>,
+    #[prop(optional)] background: Option<Color>,
+    #[prop(optional)] plot_background: Option<Color>,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+    #[prop(default = false)] gradient: bool,
+    #[prop(default = false)] show_zero_line: bool,
+    #[prop(optional)] x_ticks: Option<usize>,
+    #[prop(optional)] y_ticks: Option<usize>,
+    #[prop(default = true)] nice_axis: bool,
+    #[prop(default = false)] crosshair: bool,
+    #[prop(optional)] shared_cursor: Option<RwSignal<Option<f64>>>,
+    #[prop(optional)] on_brush: Option<Callback<(f64, f64)>>,
+    #[prop(default = false)] zoomable: bool,
+    #[prop(default = YAxisSide::Left)] y_axis_side: YAxisSide,
+    #[prop(default = false)] y_reverse: bool,
+    #[prop(default = Dir::Ltr)] dir: Dir,
+    #[prop(default = false)] keyboard_nav: bool,
+) -> impl IntoView {
+    // For animate: the points of the line rendered last, to move from them on an update
+    let previous_points = store_value(Vec::new());
+    move || {
+        LineChart(LineChartProps {
+            chart: chart.get(),
+            color: color.clone(),
+            orientation,
+            stroke_width,
+            color_series: color_series.clone(),
+            color_low: color_low.clone(),
+            point_radius,
+            point_color: point_color.clone(),
+            show_points,
+            max_points,
+            gaps,
+            y_errors: y_errors.clone(),
+            point_labels,
+            point_label_format,
+            label_every,
+            kind,
+            coord_precision,
+            fill_area,
+            tooltips,
+            grid,
+            x_title: x_title.clone(),
+            y_title: y_title.clone(),
+            y_scale,
+            node_ref,
+            responsive,
+            fixed_size,
+            style,
+            line_join,
+            line_cap,
+            marker,
+            axis_color: axis_color.clone(),
+            animate,
+            animate_ms,
+            previous_points: Some(previous_points),
+            title: title.clone(),
+            subtitle: subtitle.clone(),
+            title_size,
+            subtitle_size,
+            x_tick_format,
+            x_label_rotation,
+            x_label_max_chars,
+            x_label_wrap,
+            y_tick_format,
+            h_lines: h_lines.clone(),
+            bands: bands.clone(),
+            annotations: annotations.clone(),
+            y_begin_zero,
+            x_time,
+            aria_label: aria_label.clone(),
+            empty_message: empty_message.clone(),
+            background: background.clone(),
+            plot_background: plot_background.clone(),
+            tick_size,
+            tick_dir,
+            theme: theme.clone(),
+            class_prefix: class_prefix.clone(),
+            debug,
+            debug_color: debug_color.clone(),
+            on_error,
+            clip,
+            gradient,
+            show_zero_line,
+            x_ticks,
+            y_ticks,
+            nice_axis,
+            crosshair,
+            shared_cursor,
+            on_brush,
+            zoomable,
+            y_axis_side,
+            y_reverse,
+            dir,
+            keyboard_nav,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Values of the attribute `name` of the elements starting with `start`, in their order
+    fn attributes(html: &str, start: &str, name: &str) -> Vec<f64> {
+        html.split(start)
+            .skip(1)
+            .filter_map(|element| {
+                let value = element.split(&format!(" {}=\"", name)).nth(1)?;
+                value.split('"').next()?.parse().ok()
+            })
+            .collect()
+    }
+
+    // Vertical positions in the view of the markers of a line of the values 0, 5 and 10,
+    // and the top and bottom of its clip
+    fn rendered_positions(y_reverse: bool) -> (Vec<f64>, (f64, f64)) {
+        let chart = coord::Cartesian::new(
+            Series::from(vec![1.0, 2.0, 3.]),
+            Series::from(vec![0.0, 5.0, 10.]),
+        )
+        .set_view(820, 620, 3, 100, 100, 20);
+        let html = leptos::ssr::render_to_string(move || {
+            view! { <LineChart chart=chart y_reverse=y_reverse nice_axis=false/> }
+        });
+        let translate = html
+            .split("class=\"inner-chart\" transform=\"translate(")
+            .nth(1);
+        let origin_y = translate
+            .and_then(|translate| translate.split([',', ')']).nth(1)?.parse::<f64>().ok())
+            .unwrap();
+        let markers = html.split("class=\"markers\"").nth(1).unwrap();
+        let markers = attributes(markers.split("</g>").next().unwrap(), "<circle", "cy")
+            .into_iter()
+            .map(|cy| origin_y + cy)
+            .collect();
+        let clip = html.split("<clipPath").nth(1).unwrap();
+        let (top, height) = (
+            attributes(clip, "<rect", "y")[0],
+            attributes(clip, "<rect", "height")[0],
+        );
+        (markers, (origin_y + top, origin_y + top + height))
+    }
+
+    #[test]
+    fn y_reverse_draws_the_minimum_at_the_top() {
+        let (markers, clip) = rendered_positions(false);
+        assert_eq!(markers, vec![480., 240., 0.]);
+        let (reversed, reversed_clip) = rendered_positions(true);
+        assert_eq!(reversed, vec![0., 240., 480.]);
+        // The clip is the region of the reversed chart, keeping its points in view
+        assert_eq!(reversed_clip, clip);
+        assert!(reversed.iter().all(|y| *y > clip.0 && *y < clip.1));
+    }
+
+    // Text of the first attribute `name` after the start of `html`
+    fn text(html: &str, name: &str) -> String {
+        let value = html.split(&format!(" {}=\"", name)).nth(1).unwrap();
+        value.split('"').next().unwrap().to_string()
+    }
+
+    #[test]
+    fn animate_moves_the_line_from_its_previous_points() {
+        let chart = |values: Vec<f64>| {
+            coord::Cartesian::new(Series::from(vec![1.0, 2.0, 3.]), Series::from(values))
+                .set_view(820, 620, 3, 100, 100, 20)
+        };
+        let (first, second) = (chart(vec![0.0, 5.0, 10.]), chart(vec![10.0, 5.0, 0.]));
+        let html = leptos::ssr::render_to_string(move || {
+            let previous_points = store_value(vec![]);
+            view! {
+              <LineChart chart=first animate=true previous_points=previous_points/>
+              <LineChart chart=second animate=true previous_points=previous_points/>
+            }
+        });
+        let (first, second) = html.split_once("</svg>").unwrap();
+        let line = |html: &str| {
+            let line = html.split("class=\"line\"").nth(1).unwrap();
+            let animate = line.split("<animate").nth(1).unwrap();
+            (text(line, "d"), text(animate, "from"), text(animate, "to"))
+        };
+        let ((first_d, first_from, first_to), (second_d, second_from, second_to)) =
+            (line(first), line(second));
+
+        // The first line grows from the baseline, the second one moves from the first one
+        assert_ne!(first_from, first_d);
+        assert_eq!(first_to, first_d);
+        assert_eq!(second_from, first_d);
+        assert_eq!(second_to, second_d);
+        assert_ne!(second_d, first_d);
+
+        // The markers fade in first, then move from the previous points
+        assert!(!first.contains("<animateTransform"));
+        let markers = second.split("class=\"markers\"").nth(1).unwrap();
+        assert_eq!(markers.matches("<animateTransform").count(), 3);
+        let motion = markers.split("<animateTransform").nth(1).unwrap();
+        assert_eq!(text(motion, "from"), "0 480");
     }
 }
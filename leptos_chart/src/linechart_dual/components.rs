@@ -0,0 +1,317 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        build_line_points, check_lengths, check_series, class_name, clip_path, error_message,
+        gen_line_path, report_error, same_series, series_color, theme_axis_color, ChartError,
+        ChartTheme, DebugRegion, EmptyMessage, LineKind, SvgChart, TickDir,
+    },
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{
+    color::Color,
+    coord,
+    coord::{Point, Rec, Vector},
+};
+
+/// Component DualAxisLineChart for leptos
+///
+/// Overlays the lines of two charts sharing their x series, each with its own y-axis:
+/// the first chart on the y-axis of the view, the second on a y-axis at the opposite edge.
+/// Useful to compare correlated metrics of different scales, e.g. temperature and humidity.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["DualAxisLineChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let hours = vec![0., 4., 8., 12., 16., 20.];
+///     let chart = Cartesian::new(
+///         Series::from(hours.clone()),
+///         Series::from(vec![12., 11., 15., 22., 24., 17.]),
+///     )
+///     .set_view(820, 620, 3, 50, 60, 20);
+///     let chart_right = Cartesian::new(
+///         Series::from(hours),
+///         Series::from(vec![85., 90., 70., 45., 40., 60.]),
+///     );
+///
+///     view!{
+///         // color, color_right, y_title and y_title_right are options
+///         <DualAxisLineChart
+///             chart=chart
+///             chart_right=chart_right
+///             y_title="°C"
+///             y_title_right="%"
+///         />
+///     }
+/// }
+/// ```
+/// ## Set view for DualAxisLineChart
+/// ```ignore
+///     ...
+///     .set_view(820, 620, 3, 50, 60, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis, the same width is taken from the chart for the second one
+/// - `margin` : Margin for actual chart
+///
+/// Only the view of `chart` is used, `chart_right` needs no `set_view`.
+///
+/// ## About position_axes
+///
+/// - Top Left: 0
+/// - Top Right: 1
+/// - Bottom Right: 2
+/// - Bottom Left: 3
+///
+/// ## About color
+///
+/// The line of `chart` and its y-axis have the `color`, the ones of `chart_right` have
+/// `color_right` (default: the hue of `color` shifted by 180 degrees, or the second color of
+/// the palette of the `theme`), to tell the axes apart.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn DualAxisLineChart(
+    chart: coord::Cartesian,
+    chart_right: coord::Cartesian,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(optional)] color_right: Option<Color>,
+    #[prop(default = 2.0)] stroke_width: f64,
+    #[prop(optional, into)] y_title: Option<String>,
+    #[prop(optional, into)] y_title_right: Option<String>,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Line chart of {} points on the left axis and {} points on the right axis",
+        chart.get_ax().get_count(),
+        chart_right.get_ax().get_count()
+    );
+    let axis_color = theme_axis_color(None, &theme);
+    let checked = check_series(&chart.get_ax(), &chart.get_ay())
+        .and_then(|_| check_lengths(&chart.get_ax(), &chart.get_ay()))
+        .and_then(|_| check_series(&chart_right.get_ax(), &chart_right.get_ay()))
+        .and_then(|_| check_lengths(&chart_right.get_ax(), &chart_right.get_ay()))
+        .and_then(
+            |_| match same_series(&chart.get_ax(), &chart_right.get_ax()) {
+                true => Ok(()),
+                false => Err(ChartError::MismatchedSeries(1)),
+            },
+        );
+    if let Err(error) = checked {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+    let color_right = color_right.unwrap_or_else(|| series_color(&theme, &color, 180., 1));
+
+    // For Chart: narrowed by the width of the y-axis, for the second one at its other edge
+    let rec_ya = cview.get_rec_y_axis();
+    let width_ya = rec_ya.get_vector().get_x();
+    let rec_chart = cview.get_rec_chart();
+    let vector = Vector::new(
+        rec_chart.get_vector().get_x() + width_ya,
+        rec_chart.get_vector().get_y(),
+    );
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For x-axis
+    let rec_xa = cview.get_rec_x_axis();
+    let rec_xa = Rec::new(
+        rec_xa.get_origin(),
+        Vector::new(
+            rec_xa.get_vector().get_x() + width_ya,
+            rec_xa.get_vector().get_y(),
+        ),
+    );
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+    let series_x = chart.get_ax();
+    let axes_x = series_x.gen_axes();
+
+    // For y-axis of chart
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+    let series_y = chart.get_ay();
+    let axes_y = series_y.gen_axes();
+
+    // For y-axis of chart_right: mirrored at the other edge of the chart
+    let rec_ya_right = Rec::new(
+        Point::new(
+            rec_chart.get_origin().get_x() + vector.get_x(),
+            rec_ya.get_origin().get_y(),
+        ),
+        Vector::new(-width_ya, rec_ya.get_vector().get_y()),
+    );
+    let translate_ya_right = format!(
+        "translate({},{})",
+        rec_ya_right.get_origin().get_x(),
+        rec_ya_right.get_origin().get_y()
+    );
+    let series_y_right = chart_right.get_ay();
+    let axes_y_right = series_y_right.gen_axes();
+
+    // For chart: a line per chart, on the scale of its y-axis
+    let lines = [
+        (
+            build_line_points(&series_x, &series_y, &vector),
+            color.clone(),
+        ),
+        (
+            build_line_points(&series_x, &series_y_right, &vector),
+            color_right.clone(),
+        ),
+    ];
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=Some(color)
+              title=y_title
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis-right") transform=translate_ya_right>
+            <YAxis
+              region=rec_ya_right
+              axes=axes_y_right
+              color=Some(color_right)
+              title=y_title_right
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
+          // For draw region of chart
+
+          <DebugRegion vector=vector.clone()/>
+
+          {lines
+              .into_iter()
+              .map(|(points, color)| {
+                  let line = gen_line_path(&points, LineKind::Linear, 0);
+                  view! {
+                    <g class=class_name("series")>
+                      {points
+                          .into_iter()
+                          .map(|(x, y)| {
+                              view! { <circle cx=x cy=y r="3" fill=color.to_string_hex()></circle> }
+                          })
+                          .collect::<Vec<_>>()}
+                      <path
+                        class=class_name("line")
+                        d=line
+                        stroke=color.to_string_hex()
+                        fill="none"
+                        stroke-width=stroke_width
+                      ></path>
+                    </g>
+                  }
+              })
+              .collect::<Vec<_>>()}
+
+        </g>
+      </SvgChart>
+    }
+}
@@ -1,11 +1,20 @@
 use crate::{
     axes::{XAxis, YAxis},
-    core::SvgChart,
+    core::{
+        check_group, class_name, clip_path, error_message, legend_layout, report_error,
+        series_color, theme_axis_color, ChartError, ChartTheme, DebugRegion, EmptyMessage, Legend,
+        LegendPos, LineSeriesStyle, SvgChart, TickDir, REM,
+    },
+};
+use leptos::{
+    component, create_rw_signal, svg::Svg, view, Callback, IntoView, NodeRef, SignalGet, SignalSet,
 };
-use leptos::{component, view, IntoView};
 use theta_chart::{color::Color, coord, series::Series};
 
-/// Component LineChart for leptos
+/// Component LineChartGroup for leptos
+///
+/// Draws one line per series of the group, colored with `shift_degrees`.
+/// The axes span all the series. Also available as `MultiLineChart`.
 ///
 /// # Examples
 ///
@@ -41,7 +50,7 @@ use theta_chart::{color::Color, coord, series::Series};
 ///     }
 /// }
 /// ```
-/// ## Set view for LineChart
+/// ## Set view for LineChartGroup
 /// ```ignore
 ///     ...
 ///     .set_view(820, 620, 3, 100, 100, 20);
@@ -62,17 +71,177 @@ use theta_chart::{color::Color, coord, series::Series};
 /// - Bottom Right: 2
 /// - Bottom Left: 3
 ///
+/// ## About legend
+///
+/// Set `legend=true` to draw a legend at the side `legend_pos` of the chart,
+/// with a name per series from `series_names` (default "Series 1", "Series 2", ...).
+/// Click an entry of the legend to hide or show its line, the axes stay fixed.
+///
+/// - `LegendPos::Right` (default), `LegendPos::Left`: the entries in a column
+/// - `LegendPos::Top`, `LegendPos::Bottom`: the entries in a row
+///
+/// The region of the chart shrinks to make room for the legend.
+///
+/// ## About series_styles
+///
+/// Optional [`LineSeriesStyle`](crate::LineSeriesStyle) of each series, by index: the width
+/// and the dash pattern of its line, and a color replacing the one shifted by
+/// `shift_degrees`, in the legend too. The series without style are solid lines of width 2.
+///
+/// ## About hover_emphasis
+///
+/// Set `hover_emphasis=true` to dim the other series when the pointer is over one series,
+/// to focus on it in a busy chart. Each series also has the class `series-{index}`.
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About node_ref
+///
+/// Optional reference to the rendered `<svg>`, e.g. to save the chart with
+/// [`export_svg`](crate::export_svg) and [`download_svg`](crate::download_svg),
+/// or to measure it with `get_bounding_client_rect()`.
+///
+/// ## About responsive
+///
+/// Set `responsive=true` to scale the chart to the width of its container,
+/// keeping the aspect ratio of `set_view`, or `fixed_size=true` to give the chart the
+/// pixel size of `set_view`; else the `<svg>` only has a `viewBox`, sized by its CSS.
+///
+/// ## About title
+///
+/// Optional `title` and `subtitle`, centered in a space reserved above the chart,
+/// with the font sizes `title_size` (default 20) and `subtitle_size` (default 14).
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font. The series take the
+/// colors of its palette instead of shifting the hue of `color`, in the legend too.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[doc(alias = "MultiLineChart")]
 #[allow(non_snake_case)]
 #[component]
 pub fn LineChartGroup(
     chart: coord::CartesianGroup,
     #[prop(default = Color::default())] color: Color,
     #[prop(default = 70.)] shift_degrees: f32,
+    #[prop(default = false)] legend: bool,
+    #[prop(default = LegendPos::Right)] legend_pos: LegendPos,
+    #[prop(optional, into)] series_names: Vec<String>,
+    #[prop(default = false)] hover_emphasis: bool,
+    #[prop(optional)] series_styles: Option<Vec<LineSeriesStyle>>,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+    #[prop(default = false)] responsive: bool,
+    #[prop(default = false)] fixed_size: bool,
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] subtitle: Option<String>,
+    #[prop(default = 1.25 * REM)] title_size: f64,
+    #[prop(default = 0.875 * REM)] subtitle_size: f64,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
 ) -> impl IntoView {
     let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Line chart of {} series with {} points per series",
+        chart.get_data().len(),
+        chart.get_data().first().map_or(0, |(x, _)| x.get_count())
+    );
+    let axis_color = theme_axis_color(None, &theme);
+    if let Err(error) = check_group(&chart) {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            node_ref=node_ref
+            responsive=responsive
+            fixed_size=fixed_size
+            title=title
+            subtitle=subtitle
+            title_size=title_size
+            subtitle_size=subtitle_size
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For series_styles: the style of each series, the default one when missing,
+    // its color else the one shifted for the series
+    let series_styles = series_styles.unwrap_or_default();
+    let style = move |index: usize| series_styles.get(index).cloned().unwrap_or_default();
+    let line_color = {
+        let style = style.clone();
+        let theme = theme.clone();
+        move |index: usize| {
+            style(index)
+                .color
+                .unwrap_or_else(|| series_color(&theme, &color, shift_degrees, index))
+        }
+    };
+
+    // For legend: same colors as the lines, in a room taken at the side legend_pos
+    let legend_items = (0..chart.get_data().len())
+        .map(|index| {
+            let name = series_names
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| format!("Series {}", index + 1));
+            (name, line_color(index))
+        })
+        .collect::<Vec<_>>();
+    // For visibility of the series, toggled by a click on the legend
+    let visible = legend_items
+        .iter()
+        .map(|_| create_rw_signal(true))
+        .collect::<Vec<_>>();
+    let ((rec_chart, rec_xa, rec_ya), translate_legend) = legend_layout(
+        legend.then_some(legend_pos),
+        &legend_items,
+        (
+            cview.get_rec_chart(),
+            cview.get_rec_x_axis(),
+            cview.get_rec_y_axis(),
+        ),
+    );
+    let horizontal = matches!(legend_pos, LegendPos::Top | LegendPos::Bottom);
 
     // For Chart
-    let rec_chart = cview.get_rec_chart();
     let translate_chart = format!(
         "translate({},{})",
         rec_chart.get_origin().get_x(),
@@ -80,7 +249,6 @@ pub fn LineChartGroup(
     );
 
     // For x-axis
-    let rec_xa = cview.get_rec_x_axis();
     let translate_xa = format!(
         "translate({},{})",
         rec_xa.get_origin().get_x(),
@@ -90,7 +258,6 @@ pub fn LineChartGroup(
     let axes_x = series_x_group.gen_axes();
 
     // For y-axis
-    let rec_ya = cview.get_rec_y_axis();
     let translate_ya = format!(
         "translate({},{})",
         rec_ya.get_origin().get_x(),
@@ -109,45 +276,52 @@ pub fn LineChartGroup(
         yseries.push(tup.1);
     }
 
+    // For emphasis of the hovered series, the others are dimmed
+    let highlighted = create_rw_signal::<Option<usize>>(None);
+
     view! {
-      <SvgChart cview=cview>
-        <g class="axes">
-          <g class="x-axis" transform=translate_xa>
-            <XAxis region=rec_xa axes=axes_x/>
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        node_ref=node_ref
+        responsive=responsive
+        fixed_size=fixed_size
+        title=title
+        subtitle=subtitle
+        title_size=title_size
+        subtitle_size=subtitle_size
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme.clone()
+        clip=clip
+        region=Some(rec_chart.clone())
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
-          <g class="y-axis" transform=translate_ya>
-            <YAxis region=rec_ya axes=axes_y/>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
         </g>
-        <g class="inner-chart" transform=translate_chart>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
           // For draw region of chart
 
-          {#[cfg(feature = "debug")]
-          {
-              let vector = rec_chart.get_vector();
-              let path = format!(
-                  "M {},{} l {},{} l {},{} l {},{} Z",
-                  0,
-                  0,
-                  vector.get_x(),
-                  0,
-                  0,
-                  vector.get_y(),
-                  -vector.get_x(),
-                  0,
-              );
-              view! {
-                <circle id="originY" cx="0" cy="0" r="3"></circle>
-                <line
-                  x1="0"
-                  y1="0"
-                  x2=vector.get_x()
-                  y2=vector.get_y()
-                  style="stroke:#00ff0033;stroke-width:2"
-                ></line>
-                <path id="regionY" d=path fill="#00ff0033"></path>
-              }
-          }}
+          <DebugRegion vector=rec_chart.get_vector()/>
 
           {
               let vector = rec_chart.get_vector();
@@ -155,7 +329,8 @@ pub fn LineChartGroup(
                   .into_iter()
                   .enumerate()
                   .map(|(index, datax)| {
-                      let color = color.shift_hue_degrees_index(shift_degrees, index);
+                      let color = line_color(index);
+                      let style = style(index);
                       let xsticks = datax.to_stick();
                       let ysticks = yseries[index].to_stick();
                       let mut line = "M".to_string();
@@ -169,15 +344,84 @@ pub fn LineChartGroup(
                               view! { <circle cx=x cy=y r="3" fill=color.to_string_hex()></circle> }
                           })
                           .collect::<Vec<_>>();
+                      let shown = visible[index];
+                      let class = format!(
+                          "{} {}",
+                          class_name("series"),
+                          class_name(&format!("series-{}", index))
+                      );
+                      let dimmed = move || {
+                          highlighted.get().is_some_and(|other| other != index).then_some(0.3)
+                      };
                       view! {
-                        {point}
-                        <path d=line stroke=color.to_string_hex() fill="none" stroke-width=2></path>
+                        <g
+                          class=class
+                          display=move || (!shown.get()).then_some("none")
+                          opacity=dimmed
+                          on:mouseenter=move |_| {
+                              if hover_emphasis {
+                                  highlighted.set(Some(index));
+                              }
+                          }
+                          on:mouseleave=move |_| highlighted.set(None)
+                        >
+                          {point}
+                          <path
+                            class=class_name("line")
+                            d=line
+                            stroke=color.to_string_hex()
+                            fill="none"
+                            stroke-width=style.stroke_width
+                            stroke-dasharray=style.style.dasharray()
+                          ></path>
+                        </g>
                       }
                   })
                   .collect::<Vec<_>>()
           }
 
         </g>
+
+        {legend
+            .then(|| {
+                view! {
+                  <g transform=translate_legend>
+                    <Legend items=legend_items visible=visible.clone() horizontal=horizontal/>
+                  </g>
+                }
+            })}
       </SvgChart>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The chart of a group with a title, rendered to a string
+    fn render(chart: coord::CartesianGroup) -> String {
+        let chart = chart.set_view(820, 620, 3, 100, 100, 20);
+        leptos::ssr::render_to_string(move || view! { <LineChartGroup chart=chart title="Sales"/> })
+            .to_string()
+    }
+
+    #[test]
+    fn renders_a_group_without_data_or_with_an_error() {
+        let empty = render(coord::CartesianGroup::new());
+        assert!(empty.contains("Sales"));
+
+        let mismatched = render(
+            coord::CartesianGroup::new()
+                .add_data(Series::from(vec![1., 2.]), Series::from(vec![1.])),
+        );
+        assert!(mismatched.contains("Sales"));
+        assert!(mismatched.contains("2 x values and 1 y values in group 0"));
+
+        let chart = render(
+            coord::CartesianGroup::new()
+                .add_data(Series::from(vec![1., 2.]), Series::from(vec![1., 3.])),
+        );
+        assert!(chart.contains("Sales"));
+        assert!(!chart.contains("in group"));
+    }
+}
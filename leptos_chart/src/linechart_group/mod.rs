@@ -1,3 +1,8 @@
 mod components;
 pub use self::components::LineChartGroup;
+
+/// Same as [`LineChartGroup`]: one line per series, on axes shared by all series
+pub use self::components::LineChartGroup as MultiLineChart;
+// Also re-exported by the other charts of these coordinates, unused when one is enabled too
+#[allow(unused_imports)]
 pub use theta_chart::coord::CartesianGroup;
@@ -1,6 +1,10 @@
-use crate::core::{SvgPolar, REM};
-use leptos::{component, view, IntoView};
-use theta_chart::{chart::ScaleNumber, color::Color, coord};
+use crate::core::{
+    check_polar, check_total, circle_region, class_name, error_message, gen_ring_path,
+    report_error, series_color, ChartError, ChartTheme, DebugCircle, DebugRegion, EmptyMessage,
+    PolarScale, SvgPolar, REM,
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{color::Color, coord};
 
 /// Component PieChart for leptos
 ///
@@ -55,19 +59,67 @@ use theta_chart::{chart::ScaleNumber, color::Color, coord};
 /// - Bottom: 2
 /// - Left: 3
 ///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the color of the texts and the font. The slices take the colors of
+/// its palette instead of shifting the hue of `color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart in `debug_color`
+/// (default green), e.g. to tune the parameters of `set_view` in a deployed build.
+/// The feature debug draws them in all the charts.
+///
 #[allow(non_snake_case)]
 #[component]
 pub fn PieChart(
     chart: coord::Polar,
     #[prop(default = Color::default())] color: Color,
     #[prop(default = 70.)] shift_degrees: f32,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
 ) -> impl IntoView {
     let pview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!("Pie chart of {} slices", chart.get_data().series().len());
+    if let Err(error) = check_polar(&chart).and_then(|_| check_total(&chart)) {
+        report_error(&error, on_error);
+        let region = circle_region(&pview);
+        return view! {
+          <SvgPolar
+            pview=pview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgPolar>
+        };
+    }
 
     // For processing SNumber
     let data = chart.get_data();
-    let vec_arc = data.gen_pie();
     let series = data.series();
+    // Angles of the slices (start, end)
+    let slices = chart.angles();
 
     // For processing SLabel
     let slabel = chart.get_label();
@@ -89,44 +141,27 @@ pub fn PieChart(
     );
 
     view! {
-      <SvgPolar pview=pview>
+      <SvgPolar
+        pview=pview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme.clone()
+      >
 
-        <g class="labels" transform=translate_label>
+        <g class=class_name("labels") transform=translate_label>
           // For draw region of label
 
-          {#[cfg(feature = "debug")]
-          {
-              let vector = rec_label.get_vector();
-              let path = format!(
-                  "M {},{} l {},{} l {},{} l {},{} Z",
-                  0,
-                  0,
-                  vector.get_x(),
-                  0,
-                  0,
-                  vector.get_y(),
-                  -vector.get_x(),
-                  0,
-              );
-              view! {
-                <circle id="origin" cx="0" cy="0" r="3"></circle>
-                <line
-                  x1="0"
-                  y1="0"
-                  x2=vector.get_x()
-                  y2=vector.get_y()
-                  style="stroke:#005bbe33;stroke-width:1"
-                ></line>
-                <path id="regionX" d=path fill="#005bbe33"></path>
-              }
-          }}
+          <DebugRegion vector=rec_label.get_vector()/>
 
           {slabel
               .labels()
               .into_iter()
               .enumerate()
               .map(|(index, label)| {
-                  let color = color.shift_hue_degrees_index(shift_degrees, index);
+                  let color = series_color(&theme, &color, shift_degrees, index);
                   let py = index as f64 * 1.5 * REM;
                   view! {
                     <text x=1.5 * REM y=py dominant-baseline="text-before-edge">
@@ -144,30 +179,22 @@ pub fn PieChart(
               .collect::<Vec<_>>()}
 
         </g>
-        <g class="inner-chart" transform=translate_chart>
+        <g class=class_name("inner-chart") transform=translate_chart>
 
-          {#[cfg(all(feature = "debug"))]
-          {
-              let radius = circle_chart.get_radius();
-              view! {
-                <circle id="origin" cx=0 cy=0 r=3></circle>
-                <circle id="circle" cx=0 cy=0 r=radius fill="#00ff0033"></circle>
-                <line x1="0" y1="0" x2=0 y2=-radius style="stroke:#00ff0033;stroke-width:2"></line>
-              }
-          }}
+          <DebugCircle radius=circle_chart.get_radius()/>
 
-          {vec_arc
+          {slices
               .into_iter()
               .enumerate()
-              .map(|(index, data)| {
-                  let color = color.shift_hue_degrees_index(shift_degrees, index);
+              .map(|(index, (start, end))| {
+                  let color = series_color(&theme, &color, shift_degrees, index);
                   let radius = circle_chart.get_radius();
                   view! {
                     <path
                       fill=color.to_string_hex()
                       stroke="#ffffff"
                       stroke-width="1"
-                      d=data.gen_path(radius)
+                      d=gen_ring_path(start, end - start, radius, 0.)
                     ></path>
                   }
               })
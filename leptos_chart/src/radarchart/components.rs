@@ -1,5 +1,8 @@
-use crate::core::SvgPolar;
-use leptos::{component, view, IntoView};
+use crate::core::{
+    check_polar, circle_region, class_name, error_message, report_error, ChartError, ChartTheme,
+    DebugCircle, EmptyMessage, SvgPolar,
+};
+use leptos::{component, view, Callback, IntoView};
 use theta_chart::{chart::ScaleNumber, color::Color, coord};
 
 /// Component RadarChart for leptos
@@ -56,13 +59,59 @@ use theta_chart::{chart::ScaleNumber, color::Color, coord};
 /// - Bottom: 2
 /// - Left: 3
 ///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the color of the texts and the font.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart in `debug_color`
+/// (default green), e.g. to tune the parameters of `set_view` in a deployed build.
+/// The feature debug draws them in all the charts.
+///
 #[allow(non_snake_case)]
 #[component]
 pub fn RadarChart(
     chart: coord::Polar,
     #[prop(default = Color::default())] color: Color,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
 ) -> impl IntoView {
     let pview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!("Radar chart of {} axes", chart.get_data().series().len());
+    if let Err(error) = check_polar(&chart) {
+        report_error(&error, on_error);
+        let region = circle_region(&pview);
+        return view! {
+          <SvgPolar
+            pview=pview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgPolar>
+        };
+    }
 
     // For processing SNumber
     let data = chart.get_data();
@@ -81,14 +130,18 @@ pub fn RadarChart(
     );
 
     view! {
-      <SvgPolar pview=pview>
-        <g class="inner-chart" transform=translate_chart>
+      <SvgPolar
+        pview=pview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+      >
+        <g class=class_name("inner-chart") transform=translate_chart>
 
-          {#[cfg(all(feature = "debug"))]
-          {
-              let radius = circle_chart.get_radius();
-              view! { <circle id="circle" cx=0 cy=0 r=radius fill="#00ff0033"></circle> }
-          }}
+          <DebugCircle radius=circle_chart.get_radius()/>
 
           // For grid
 
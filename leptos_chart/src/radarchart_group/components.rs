@@ -0,0 +1,322 @@
+use crate::core::{
+    check_group, class_name, error_message, include_zero, legend_layout, point_on_circle,
+    report_error, series_color, ChartError, ChartTheme, DebugCircle, EmptyMessage, Legend,
+    LegendPos, SvgChart, REM,
+};
+use leptos::{component, create_rw_signal, view, Callback, IntoView, SignalGet};
+use std::f64::consts::TAU;
+use theta_chart::{color::Color, coord, series::Series};
+
+/// Component RadarChartGroup for leptos
+///
+/// Compares several series (entities) on the axes given by the labels of the x series:
+/// one filled polygon per series, colored with `shift_degrees`.
+/// The radius of a point is its value, scaled on all the series from zero.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["RadarChartGroup"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let skills = vec!["Reading", "Writing", "Listening", "Speaking", "React"];
+///     let chart = CartesianGroup::new()
+///         .set_view(740, 540, 3, 0, 0, 20)
+///         .add_data(
+///             Series::from(skills.clone()),
+///             Series::from(vec![85.0, 55.0, 45., 60., 40.]),
+///         )
+///         .add_data(
+///             Series::from(skills),
+///             Series::from(vec![40.0, 75.0, 65., 30., 90.]),
+///         );
+///
+///     view!{
+///         // color and shift_degrees are options
+///         <RadarChartGroup chart=chart legend=true />
+///     }
+/// }
+/// ```
+/// ## Set view for RadarChartGroup
+/// ```ignore
+///     ...
+///     .set_view(740, 540, 3, 0, 0, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis, no x-axis is drawn so 0 is fine
+/// - `width_y_axis` : Width y_axis, no y-axis is drawn so 0 is fine
+/// - `margin` : Margin for actual chart
+///
+/// ## About rings
+///
+/// The concentric polygons are drawn at the sticks of the values, with their labels
+/// along the first axis, which points up. The axes go clockwise.
+///
+/// ## About legend
+///
+/// Set `legend=true` to draw a legend at the side `legend_pos` of the chart,
+/// with a name per series from `series_names` (default "Series 1", "Series 2", ...).
+/// Click an entry of the legend to hide or show its polygon, the rings stay fixed.
+///
+/// - `LegendPos::Right` (default), `LegendPos::Left`: the entries in a column
+/// - `LegendPos::Top`, `LegendPos::Bottom`: the entries in a row
+///
+/// The radar shrinks to make room for the legend.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the color of the texts and the font. The series take the colors of
+/// its palette instead of shifting the hue of `color`, in the legend too.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart in `debug_color`
+/// (default green), e.g. to tune the parameters of `set_view` in a deployed build.
+/// The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn RadarChartGroup(
+    chart: coord::CartesianGroup,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(default = 70.)] shift_degrees: f32,
+    #[prop(default = false)] legend: bool,
+    #[prop(default = LegendPos::Right)] legend_pos: LegendPos,
+    #[prop(optional, into)] series_names: Vec<String>,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!(
+        "Radar chart of {} series with {} axes per series",
+        chart.get_data().len(),
+        chart.get_data().first().map_or(0, |(x, _)| x.get_count())
+    );
+    let series_x_group = chart.get_ax_group();
+    let checked = check_group(&chart).and_then(|_| match series_x_group {
+        Series::Label(_) => Ok(()),
+        _ => Err(ChartError::UnsupportedSeries(
+            "The axes of the radar chart need a series of labels".to_string(),
+        )),
+    });
+    if let Err(error) = checked {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For legend: same colors as the polygons, in a room taken at the side legend_pos
+    let legend_items = (0..chart.get_data().len())
+        .map(|index| {
+            let name = series_names
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| format!("Series {}", index + 1));
+            (name, series_color(&theme, &color, shift_degrees, index))
+        })
+        .collect::<Vec<_>>();
+    // For visibility of the series, toggled by a click on the legend
+    let visible = legend_items
+        .iter()
+        .map(|_| create_rw_signal(true))
+        .collect::<Vec<_>>();
+    let ((rec_chart, _, _), translate_legend) = legend_layout(
+        legend.then_some(legend_pos),
+        &legend_items,
+        (
+            cview.get_rec_chart(),
+            cview.get_rec_x_axis(),
+            cview.get_rec_y_axis(),
+        ),
+    );
+    let horizontal = matches!(legend_pos, LegendPos::Top | LegendPos::Bottom);
+
+    // For Chart: centered in the region, with room for the labels of the axes
+    let origin = rec_chart.get_origin();
+    let vector = rec_chart.get_vector();
+    let translate_chart = format!(
+        "translate({},{})",
+        origin.get_x() + vector.get_x() / 2.,
+        origin.get_y() + vector.get_y() / 2.
+    );
+    let radius = (vector.get_x().abs().min(vector.get_y().abs()) / 2. - 2. * REM).max(REM);
+
+    // For axes: one per label, clockwise from the top
+    let labels = series_x_group.to_stick();
+    let count = labels.len();
+    let direction = move |index: usize| point_on_circle(1., TAU * index as f64 / count as f64);
+    let polygon = move |scales: Vec<f64>| {
+        scales
+            .into_iter()
+            .enumerate()
+            .map(|(index, scale)| {
+                let (dx, dy) = direction(index);
+                format!("{:.1},{:.1}", dx * radius * scale, dy * radius * scale)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    // For values: the same scale for all the series, from zero
+    let series_y_group = include_zero(&chart.get_ay_group());
+    let rings = series_y_group.gen_axes().sticks;
+
+    // For chart
+    let data = chart.get_data();
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme.clone()
+      >
+        <g class=class_name("inner-chart") transform=translate_chart>
+
+          <DebugCircle radius=radius/>
+
+          // For rings
+          <g class=class_name("rings")>
+            {rings
+                .into_iter()
+                .filter(|stick| stick.value > 0.)
+                .map(|stick| {
+                    let points = polygon(vec![stick.value; count]);
+                    view! {
+                      <polygon points=points stroke="#00000022" fill="none"></polygon>
+                      <text
+                        x=REM / 4.
+                        y=-radius * stick.value
+                        dominant-baseline="middle"
+                        opacity=0.3
+                      >
+                        {stick.label}
+                      </text>
+                    }
+                })
+                .collect::<Vec<_>>()}
+          </g>
+
+          // For axes
+          <g class=class_name("axes")>
+            {labels
+                .into_iter()
+                .enumerate()
+                .map(|(index, stick)| {
+                    let (dx, dy) = direction(index);
+                    let anchor = if dx > 0.1 {
+                        "start"
+                    } else if dx < -0.1 {
+                        "end"
+                    } else {
+                        "middle"
+                    };
+                    view! {
+                      <line
+                        x1="0"
+                        y1="0"
+                        x2=dx * radius
+                        y2=dy * radius
+                        style="stroke:#00000011;stroke-width:1"
+                      ></line>
+                      <text
+                        x=dx * (radius + REM / 2.)
+                        y=dy * (radius + REM / 2.)
+                        dominant-baseline="middle"
+                        text-anchor=anchor
+                        opacity=0.5
+                      >
+                        {stick.label}
+                      </text>
+                    }
+                })
+                .collect::<Vec<_>>()}
+          </g>
+
+          {data
+              .into_iter()
+              .enumerate()
+              .map(|(index, (xseries, yseries))| {
+                  let color = series_color(&theme, &color, shift_degrees, index);
+                  // Values in the order of the axes, zero when the series misses an axis
+                  let mut scales = vec![0.; count];
+                  for (xstick, ystick) in xseries.to_stick().into_iter().zip(yseries.to_stick()) {
+                      let axis = series_x_group.scale_index(xstick.label);
+                      if let Some(scale) = scales.get_mut(axis) {
+                          *scale = series_y_group.scale(ystick.value).clamp(0., 1.);
+                      }
+                  }
+                  let points = polygon(scales);
+                  let shown = visible[index];
+                  view! {
+                    <g class=class_name("series") display=move || (!shown.get()).then_some("none")>
+                      <polygon
+                        points=points
+                        stroke=color.to_string_hex()
+                        stroke-width="2"
+                        fill=format!("{}33", color.to_string_hex())
+                      ></polygon>
+                    </g>
+                  }
+              })
+              .collect::<Vec<_>>()}
+
+        </g>
+
+        {legend
+            .then(|| {
+                view! {
+                  <g transform=translate_legend>
+                    <Legend items=legend_items visible=visible.clone() horizontal=horizontal/>
+                  </g>
+                }
+            })}
+      </SvgChart>
+    }
+}
@@ -0,0 +1,151 @@
+use crate::core::{
+    class_name, gen_line_path, provide_class_prefix, report_error, ChartError, LineKind,
+};
+use leptos::{component, svg, view, Callback, IntoView};
+use theta_chart::{color::Color, series::Series};
+
+/// Component Sparkline for leptos
+///
+/// A tiny line chart of the trend of a series, e.g. in a table or next to a metric:
+/// no axes, no margin, no title, the line fills the size of the SVG.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["Sparkline"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let data = Series::from(vec![3., 5., 4., 8., 6., 9., 12.]);
+///
+///     view!{
+///         <p>
+///             "Visits " <Sparkline data=data end_point=true /> " 12"
+///         </p>
+///     }
+/// }
+/// ```
+/// ## Arguments
+/// - `data` : The series of numbers, drawn from left to right at an even spacing
+/// - `width`, `height` : The size of the SVG (default 100 by 30)
+/// - `color` : The color of the line and of the end point
+/// - `stroke_width` : The width of the line (default 1.5)
+/// - `kind` : The interpolation of the line, [`LineKind`](crate::LineKind)
+/// - `end_point` : Draw a dot at the last value (default false)
+/// - `on_error` : Callback receiving the [`ChartError`](crate::ChartError) of a series
+///   which is not of numbers, drawn empty (optional)
+///
+/// ## About scale
+///
+/// The line spans the smallest to the largest value of the series, from the bottom to the top,
+/// inset by the half of the width of the line or by the end point, for them not to be cut.
+/// A constant series is drawn in the middle.
+///
+/// ## About class_prefix
+///
+/// Optional prefix of the classes of the elements, e.g. `class_prefix="myapp"` gives
+/// `myapp-sparkline`, `myapp-line` and `myapp-end-point`.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the sparkline for screen readers, it is also added as a `<title>`.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn Sparkline(
+    data: Series,
+    #[prop(default = 100)] width: u64,
+    #[prop(default = 30)] height: u64,
+    #[prop(default = Color::default())] color: Color,
+    #[prop(default = 1.5)] stroke_width: f64,
+    #[prop(optional)] kind: LineKind,
+    #[prop(default = false)] end_point: bool,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+) -> impl IntoView {
+    provide_class_prefix(class_prefix);
+    let (width, height) = (width as f64, height as f64);
+    let values = match data {
+        Series::Number(_) => data
+            .to_stick()
+            .into_iter()
+            .map(|stick| stick.value)
+            .filter(|value| value.is_finite())
+            .collect::<Vec<_>>(),
+        _ => {
+            let message = "The sparkline needs a series of numbers".to_string();
+            report_error(&ChartError::UnsupportedSeries(message), on_error);
+            vec![]
+        }
+    };
+
+    // For scale: the values from the bottom to the top, inset for the line and the end point
+    let radius = 1.5 * stroke_width;
+    let inset = if end_point { radius } else { stroke_width / 2. };
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let (inner_width, inner_height) = (width - 2. * inset, height - 2. * inset);
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let x = match values.len() {
+                1 => 0.5,
+                len => index as f64 / (len - 1) as f64,
+            };
+            let y = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.5
+            };
+            (inset + x * inner_width, inset + (1. - y) * inner_height)
+        })
+        .collect::<Vec<_>>();
+    let path = gen_line_path(&points, kind, 1);
+    let last = points.last().copied();
+    let color = color.to_string_hex();
+    // For accessibility: a basic description of the data
+    let desc = format!("Sparkline of {} values", values.len());
+
+    view! {
+      <svg
+        class=class_name("sparkline")
+        viewBox=format!("0 0 {} {}", width, height)
+        width=width
+        height=height
+        role="img"
+        aria-label=aria_label.clone()
+      >
+        {aria_label.map(|label| svg::title().child(label))}
+        <desc>{desc}</desc>
+        <path
+          class=class_name("line")
+          d=path
+          fill="none"
+          stroke=color.clone()
+          stroke-width=stroke_width
+          stroke-linecap="round"
+          stroke-linejoin="round"
+        ></path>
+        {last
+            .filter(|_| end_point)
+            .map(|(x, y)| {
+                view! {
+                  <circle class=class_name("end-point") cx=x cy=y r=radius fill=color></circle>
+                }
+            })}
+      </svg>
+    }
+}
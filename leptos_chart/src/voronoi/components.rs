@@ -1,8 +1,11 @@
 use crate::{
     axes::{XAxis, YAxis},
-    core::SvgChart,
+    core::{
+        check_lengths, check_series, class_name, error_message, report_error, theme_axis_color,
+        ChartError, ChartTheme, DebugRegion, EmptyMessage, SvgChart, TickDir,
+    },
 };
-use leptos::{component, view, IntoView, Show};
+use leptos::{component, view, Callback, IntoView, Show};
 use theta_chart::{color::Color, coord, delaunator::*};
 
 /// Component ScatterChart for leptos
@@ -75,14 +78,71 @@ use theta_chart::{color::Color, coord, delaunator::*};
 /// - Bottom Right: 2
 /// - Bottom Left: 3
 ///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
 #[allow(non_snake_case)]
 #[component]
 pub fn Voronoi(
     chart: coord::Cartesian,
     #[prop(default = Color::default())] color: Color,
     #[prop(default = false)] delaunay: bool,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
 ) -> impl IntoView {
     let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!("Voronoi diagram of {} points", chart.get_ax().get_count());
+    if let Err(error) = check_series(&chart.get_ax(), &chart.get_ay())
+        .and_then(|_| check_lengths(&chart.get_ax(), &chart.get_ay()))
+    {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+    let axis_color = theme_axis_color(None, &theme);
 
     // For Chart
     let rec_chart = cview.get_rec_chart();
@@ -122,13 +182,33 @@ pub fn Voronoi(
     let triangle = triangle(xseries.clone(), yseries.clone());
 
     view! {
-      <SvgChart cview=cview>
-        <g class="axes">
-          <g class="x-axis" transform=translate_xa>
-            <XAxis region=rec_xa axes=axes_x/>
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
-          <g class="y-axis" transform=translate_ya>
-            <YAxis region=rec_ya axes=axes_y/>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
           </g>
         </g>
 
@@ -141,34 +221,13 @@ pub fn Voronoi(
           ></rect>
         </clipPath>
 
-        <g class="inner-chart" transform=translate_chart clip-path="url(#imageclippath)">
+        <g
+          class=class_name("inner-chart")
+          transform=translate_chart
+          clip-path="url(#imageclippath)"
+        >
           // For draw region of chart
-          {#[cfg(feature = "debug")]
-          {
-              let vector = rec_chart.get_vector();
-              let path = format!(
-                  "M {},{} l {},{} l {},{} l {},{} Z",
-                  0,
-                  0,
-                  vector.get_x(),
-                  0,
-                  0,
-                  vector.get_y(),
-                  -vector.get_x(),
-                  0,
-              );
-              view! {
-                <circle id="origin" cx="0" cy="0" r="3"></circle>
-                <line
-                  x1="0"
-                  y1="0"
-                  x2=vector.get_x()
-                  y2=vector.get_y()
-                  style="stroke:#00ff0033;stroke-width:2"
-                ></line>
-                <path id="region" d=path fill="#00ff0033"></path>
-              }
-          }}
+          <DebugRegion vector=rec_chart.get_vector()/>
 
           // Point
 
@@ -211,7 +270,7 @@ pub fn Voronoi(
                       view! {
                         <polygon
                           points=points.clone()
-                          class="voronoi"
+                          class=class_name("voronoi")
                           fill="none"
                           stroke=color.shift_hue_degrees_index(120_f32, 2).to_string_hex()
                         ></polygon>
@@ -281,7 +340,7 @@ pub fn Voronoi(
                         view! {
                           <polygon
                             points=points.clone()
-                            class="triangle"
+                            class=class_name("triangle")
                             fill="none"
                             stroke=color.shift_hue_degrees_index(120_f32, 1).to_string_hex()
                           ></polygon>
@@ -0,0 +1,296 @@
+use crate::{
+    axes::{XAxis, YAxis},
+    core::{
+        check_lengths, check_series, class_name, clip_path, error_message, report_error,
+        theme_axis_color, ChartError, ChartTheme, DebugRegion, EmptyMessage, SvgChart, TickDir,
+    },
+};
+use leptos::{component, view, Callback, IntoView};
+use theta_chart::{
+    color::Color,
+    coord,
+    series::{SLabel, Series},
+};
+
+/// Component WaterfallChart for leptos
+///
+/// Each bar starts where the previous one ended, rising by the increases and falling by the
+/// decreases of the values, e.g. for financial flows. The bars are connected by thin lines.
+///
+/// # Examples
+///
+/// ## Cargo.toml
+///
+/// ```toml
+/// [dependencies]
+/// leptos = {version = "0.5"}
+/// leptos_chart = {version = "0.2", features = ["WaterfallChart"]}
+/// ```
+///
+/// ## Component
+/// ```ignore
+/// use leptos::*;
+/// use leptos_chart::*;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let chart = Cartesian::new(
+///         Series::from(vec!["Sales", "Services", "Costs", "Taxes"]),
+///         Series::from(vec![420., 210., -300., -90.]),
+///     )
+///     .set_view(820, 620, 3, 50, 50, 20);
+///
+///     view!{
+///         // increase_color, decrease_color and total are options
+///         <WaterfallChart chart=chart total=true />
+///     }
+/// }
+/// ```
+/// ## Set view for WaterfallChart
+/// ```ignore
+///     ...
+///     .set_view(820, 620, 3, 100, 100, 20);
+///     ...
+/// ```
+/// ## Arguments
+/// - `width` : The width of SGV
+/// - `height` : The height of SGV
+/// - `position_origin` : Positions for origin of chart xOy
+/// - `height_x_axis` : Height x_axis
+/// - `width_y_axis` : Width y_axis
+/// - `margin` : Margin for actual chart
+///
+/// ## About position_axes
+///
+/// - Top Left: 0
+/// - Top Right: 1
+/// - Bottom Right: 2
+/// - Bottom Left: 3
+///
+/// ## About colors
+///
+/// The increases have the `increase_color` (default green), the decreases the
+/// `decrease_color` (default red).
+///
+/// ## About total
+///
+/// Set `total=true` to add a last bar from zero to the final total, labeled `total_label`
+/// (default "Total") and colored `total_color` (default blue).
+///
+/// ## About tick_size and tick_dir
+///
+/// The tick marks of the axes are `tick_size` pixels long (default 6), out of the chart
+/// toward the labels with `TickDir::Outside` (default) or into it with `TickDir::Inside`.
+/// The labels move away from longer outside ticks.
+///
+/// ## About aria_label
+///
+/// The SVG has the `img` role and a generated `<desc>` summarizing the data.
+/// Set `aria_label` to name the chart for screen readers, it is also added as a `<title>`.
+///
+/// ## About theme
+///
+/// Optional [`ChartTheme`](crate::ChartTheme), e.g. `theme=ChartTheme::dark()`, giving the
+/// background, the colors of the axes and the texts, and the font. The bars keep their
+/// `increase_color` and `decrease_color`.
+///
+/// ## About on_error
+///
+/// Optional callback receiving the [`ChartError`](crate::ChartError) when the data can not
+/// be drawn, e.g. to show another UI. The chart shows the message of the error instead.
+///
+/// ## About debug
+///
+/// Set `debug=true` to draw the regions of the view over the chart, the axes in red and blue
+/// and the chart in `debug_color` (default green), e.g. to tune the parameters of `set_view`
+/// in a deployed build. The feature debug draws them in all the charts.
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn WaterfallChart(
+    chart: coord::Cartesian,
+    #[prop(default = Color::from("#2ca02c"))] increase_color: Color,
+    #[prop(default = Color::from("#d62728"))] decrease_color: Color,
+    #[prop(default = false)] total: bool,
+    #[prop(default = "Total".to_string(), into)] total_label: String,
+    #[prop(default = Color::default())] total_color: Color,
+    #[prop(default = 6.0)] tick_size: f64,
+    #[prop(default = TickDir::Outside)] tick_dir: TickDir,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional)] theme: Option<ChartTheme>,
+    #[prop(optional, into)] class_prefix: Option<String>,
+    #[prop(default = false)] debug: bool,
+    #[prop(optional)] debug_color: Option<Color>,
+    #[prop(optional)] on_error: Option<Callback<ChartError>>,
+    #[prop(default = true)] clip: bool,
+) -> impl IntoView {
+    let cview = chart.get_view();
+    // For accessibility: a basic description of the data
+    let desc = format!("Waterfall chart of {} steps", chart.get_ax().get_count());
+    let axis_color = theme_axis_color(None, &theme);
+    let checked = check_series(&chart.get_ax(), &chart.get_ay())
+        .and_then(|_| check_lengths(&chart.get_ax(), &chart.get_ay()))
+        .and_then(|_| match chart.get_ax() {
+            Series::Label(_) => Ok(()),
+            _ => Err(ChartError::UnsupportedSeries(
+                "The waterfall chart needs a series of labels on x".to_string(),
+            )),
+        });
+    if let Err(error) = checked {
+        report_error(&error, on_error);
+        let region = cview.get_rec_chart();
+        return view! {
+          <SvgChart
+            cview=cview
+            aria_label=aria_label.clone()
+            desc=Some(desc.clone())
+            class_prefix=class_prefix.clone()
+            debug=debug
+            debug_color=debug_color.clone()
+            theme=theme.clone()
+            clip=clip
+          >
+            <EmptyMessage region=region message=error_message(&error, None)/>
+          </SvgChart>
+        };
+    }
+
+    // For Chart
+    let rec_chart = cview.get_rec_chart();
+    let translate_chart = format!(
+        "translate({},{})",
+        rec_chart.get_origin().get_x(),
+        rec_chart.get_origin().get_y()
+    );
+
+    // For bars: from the running total before the value to the one after it,
+    // then from zero to the final total
+    let mut running = 0.;
+    let mut bars = chart
+        .get_ax()
+        .to_stick()
+        .into_iter()
+        .zip(chart.get_ay().to_stick())
+        .map(|(label, value)| {
+            let start = running;
+            running += value.value;
+            let color = if value.value >= 0. {
+                increase_color.clone()
+            } else {
+                decrease_color.clone()
+            };
+            (label.label, start, running, color)
+        })
+        .collect::<Vec<_>>();
+    if total {
+        bars.push((total_label, 0., running, total_color));
+    }
+
+    // For x-axis: the labels, with the one of the total
+    let rec_xa = cview.get_rec_x_axis();
+    let translate_xa = format!(
+        "translate({},{})",
+        rec_xa.get_origin().get_x(),
+        rec_xa.get_origin().get_y()
+    );
+    let series_x = Series::Label(SLabel::from(
+        bars.iter().map(|bar| bar.0.clone()).collect::<Vec<_>>(),
+    ));
+    let axes_x = series_x.gen_axes();
+
+    // For y-axis: zero and the running totals
+    let rec_ya = cview.get_rec_y_axis();
+    let translate_ya = format!(
+        "translate({},{})",
+        rec_ya.get_origin().get_x(),
+        rec_ya.get_origin().get_y()
+    );
+    let series_y = Series::from(bars.iter().map(|bar| bar.2).chain([0.]).collect::<Vec<_>>());
+    let axes_y = series_y.gen_axes();
+
+    view! {
+      <SvgChart
+        cview=cview
+        aria_label=aria_label
+        desc=Some(desc)
+        class_prefix=class_prefix
+        debug=debug
+        debug_color=debug_color
+        theme=theme
+        clip=clip
+      >
+        <g class=class_name("axes")>
+          <g class=class_name("x-axis") transform=translate_xa>
+            <XAxis
+              region=rec_xa
+              axes=axes_x
+              color=axis_color.clone()
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+          <g class=class_name("y-axis") transform=translate_ya>
+            <YAxis
+              region=rec_ya
+              axes=axes_y
+              color=axis_color
+              tick_size=tick_size
+              tick_dir=tick_dir
+            />
+          </g>
+        </g>
+        <g class=class_name("inner-chart") transform=translate_chart clip-path=clip_path()>
+          // For draw region of chart
+
+          <DebugRegion vector=rec_chart.get_vector()/>
+
+          {
+              let vector = rec_chart.get_vector();
+              let width_col = series_x.scale(0.9) * vector.get_x();
+              let count = bars.len();
+              bars.into_iter()
+                  .enumerate()
+                  .map(|(index, (_, start, end, color))| {
+                      let x = series_x.scale(index as f64 + 0.5) * vector.get_x();
+                      let y1 = series_y.scale(start) * vector.get_y();
+                      let y2 = series_y.scale(end) * vector.get_y();
+                      let style = format!(
+                          "stroke:{};stroke-width:{}",
+                          color.to_string_hex(),
+                          width_col.abs() as u64,
+                      );
+                      // Connector at the end of the bar, to the start of the next one
+                      let connector = (index + 1 < count).then(|| {
+                          let x2 = series_x.scale(index as f64 + 1.5) * vector.get_x();
+                          view! {
+                            <line
+                              class=class_name("connector")
+                              x1=x
+                              y1=y2
+                              x2=x2
+                              y2=y2
+                              stroke="currentColor"
+                              stroke-opacity="0.5"
+                              stroke-dasharray="2,2"
+                            ></line>
+                          }
+                      });
+                      view! {
+                        <line
+                          class=class_name("bar")
+                          x1=x
+                          y1=y1
+                          x2=x
+                          y2=y2
+                          style=style
+                        ></line>
+                        {connector}
+                      }
+                  })
+                  .collect::<Vec<_>>()
+          }
+
+        </g>
+      </SvgChart>
+    }
+}
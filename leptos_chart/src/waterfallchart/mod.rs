@@ -0,0 +1,5 @@
+mod components;
+pub use self::components::WaterfallChart;
+// Also re-exported by the other charts of these coordinates, unused when one is enabled too
+#[allow(unused_imports)]
+pub use theta_chart::coord::Cartesian;